@@ -6,6 +6,11 @@ pub use WCDBBridging::*;
 
 extern "C" {
     fn printVersion();
+
+    /// The wrapper of `sqlite3_bind_parameter_count`, which is not bridged by WCDB.
+    pub fn WCDBRustHandleStatementBindParameterCount(
+        handleStatement: CPPHandleStatement,
+    ) -> ::std::os::raw::c_int;
 }
 
 pub fn print_version() {
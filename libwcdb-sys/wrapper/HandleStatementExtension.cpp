@@ -0,0 +1,12 @@
+#include "HandleStatement.hpp"
+#include "HandleStatementBridge.h"
+#include "ObjectBridge.hpp"
+#include "SQLite.h"
+
+extern "C" int WCDBRustHandleStatementBindParameterCount(CPPHandleStatement handleStatement);
+
+int WCDBRustHandleStatementBindParameterCount(CPPHandleStatement handleStatement)
+{
+    WCDBGetObjectOrReturnValue(handleStatement, WCDB::HandleStatement, cppHandleStatement, 0);
+    return sqlite3_bind_parameter_count(cppHandleStatement->getRawStatement());
+}
@@ -0,0 +1,351 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, Ident, LitStr, Type};
+
+/// Derive `wcdb::orm::TableCoding` for a struct with named fields.
+///
+/// See `wcdb::orm::TableCoding` for the supported `#[wcdb(...)]` attributes.
+#[proc_macro_derive(TableCoding, attributes(wcdb))]
+pub fn derive_table_coding(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The items of `TableCoding`, which the field accessors `T::field()` would shadow.
+const TABLE_CODING_ITEMS: [&str; 7] = [
+    "binding",
+    "all_fields",
+    "encode",
+    "decode",
+    "auto_increment_field",
+    "is_auto_increment",
+    "set_last_insert_rowid",
+];
+
+struct Column {
+    ident: Ident,
+    ty: Type,
+    name: String,
+    primary: bool,
+    auto_increment: bool,
+    unique: bool,
+    not_null: bool,
+    index: bool,
+    unindexed: bool,
+}
+
+impl Column {
+    fn parse(field: &syn::Field) -> syn::Result<Column> {
+        let ident = field.ident.clone().expect("named field");
+        if TABLE_CODING_ITEMS.contains(&ident.to_string().trim_start_matches("r#")) {
+            return Err(syn::Error::new_spanned(
+                &ident,
+                "field name is taken by TableCoding, rename it and keep the column name with column_name",
+            ));
+        }
+        let mut column = Column {
+            name: ident.to_string().trim_start_matches("r#").to_string(),
+            ident,
+            ty: field.ty.clone(),
+            primary: false,
+            auto_increment: false,
+            unique: false,
+            not_null: false,
+            index: false,
+            unindexed: false,
+        };
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("wcdb")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("primary") {
+                    column.primary = true;
+                } else if meta.path.is_ident("auto_increment") {
+                    column.auto_increment = true;
+                } else if meta.path.is_ident("unique") {
+                    column.unique = true;
+                } else if meta.path.is_ident("not_null") {
+                    column.not_null = true;
+                } else if meta.path.is_ident("index") {
+                    column.index = true;
+                } else if meta.path.is_ident("unindexed") {
+                    column.unindexed = true;
+                } else if meta.path.is_ident("column_name") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    column.name = name.value();
+                } else {
+                    return Err(meta.error("unsupported wcdb attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        if column.name.is_empty() || column.name.contains('\0') {
+            return Err(syn::Error::new_spanned(
+                &field.ident,
+                "column name must be non-empty and must not contain NUL",
+            ));
+        }
+        if column.auto_increment && !column.primary {
+            return Err(syn::Error::new_spanned(
+                &field.ident,
+                "auto_increment requires primary",
+            ));
+        }
+        Ok(column)
+    }
+
+    fn binding(&self) -> TokenStream2 {
+        let Column { ty, name, .. } = self;
+        let mut tokens = quote! {
+            let binding = binding
+                .column_def(#name, <#ty as ::wcdb::orm::ColumnCoding>::column_type())
+                .unwrap();
+        };
+        let mut constraints = Vec::new();
+        if self.primary {
+            let auto_increment = self.auto_increment.then(|| quote!(.auto_increment()));
+            constraints.push(quote!(primary_key() #auto_increment));
+        }
+        if self.unique {
+            constraints.push(quote!(unique()));
+        }
+        if self.not_null {
+            constraints.push(quote!(not_null()));
+        }
+        if self.unindexed {
+            constraints.push(quote!(un_indexed()));
+        }
+        for constraint in constraints {
+            tokens.extend(quote! {
+                let binding = binding
+                    .column_constraint(
+                        #name,
+                        ::wcdb::winq::identifiers::column_constraint::ColumnConstraint::new()
+                            .#constraint,
+                    )
+                    .unwrap();
+            });
+        }
+        if self.index {
+            let suffix = format!("_{}_index", name);
+            tokens.extend(quote! {
+                let binding = binding
+                    .index(
+                        #suffix,
+                        ::wcdb::winq::statements::create_index::StatementCreateIndex::new()
+                            .indexed_by([
+                                ::wcdb::winq::identifiers::column::Column::new(#name).unwrap(),
+                            ]),
+                    )
+                    .unwrap();
+            });
+        }
+        tokens
+    }
+}
+
+/// `#[wcdb(virtual_table(module = "fts5", tokenizer = "unicode61"))]` on the struct.
+struct VirtualTable {
+    module: LitStr,
+    tokenizer: Option<LitStr>,
+}
+
+/// The `#[wcdb(...)]` attributes on the struct.
+#[derive(Default)]
+struct Table {
+    virtual_table: Option<VirtualTable>,
+    auto_increment_for_existing_table: bool,
+}
+
+impl Table {
+    fn parse(input: &DeriveInput) -> syn::Result<Table> {
+        let mut table = Table::default();
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("wcdb")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("auto_increment_for_existing_table") {
+                    table.auto_increment_for_existing_table = true;
+                    return Ok(());
+                }
+                if !meta.path.is_ident("virtual_table") {
+                    return Err(meta.error("unsupported wcdb attribute"));
+                }
+                let mut module = None;
+                let mut tokenizer = None;
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("module") {
+                        module = Some(meta.value()?.parse::<LitStr>()?);
+                    } else if meta.path.is_ident("tokenizer") {
+                        tokenizer = Some(meta.value()?.parse::<LitStr>()?);
+                    } else {
+                        return Err(meta.error("unsupported virtual_table attribute"));
+                    }
+                    Ok(())
+                })?;
+                let module = module.ok_or_else(|| meta.error("virtual_table requires module"))?;
+                for name in std::iter::once(&module).chain(&tokenizer) {
+                    if name.value().contains('\0') {
+                        return Err(syn::Error::new_spanned(name, "must not contain NUL"));
+                    }
+                }
+                table.virtual_table = Some(VirtualTable { module, tokenizer });
+                Ok(())
+            })?;
+        }
+        Ok(table)
+    }
+
+    fn binding(&self) -> TokenStream2 {
+        let mut tokens = TokenStream2::new();
+        if let Some(VirtualTable { module, tokenizer }) = &self.virtual_table {
+            tokens.extend(quote! {
+                let binding = binding.virtual_module(#module).unwrap();
+            });
+            if let Some(tokenizer) = tokenizer {
+                tokens.extend(quote! {
+                    let binding = binding.tokenizer(#tokenizer).unwrap();
+                });
+            }
+        }
+        if self.auto_increment_for_existing_table {
+            tokens.extend(quote! {
+                let binding = binding.enable_auto_increment_for_existing_table();
+            });
+        }
+        tokens
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "TableCoding can not be derived for generic types",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "TableCoding can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "TableCoding can only be derived for structs",
+            ))
+        }
+    };
+    let columns = fields
+        .iter()
+        .map(Column::parse)
+        .collect::<syn::Result<Vec<_>>>()?;
+    if columns.iter().filter(|c| c.auto_increment).count() > 1 {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "only one field can be auto_increment",
+        ));
+    }
+
+    let mut bindings: Vec<_> = columns.iter().map(Column::binding).collect();
+    bindings.push(Table::parse(&input)?.binding());
+    let names: Vec<_> = columns.iter().map(|c| &c.name).collect();
+    let idents: Vec<_> = columns.iter().map(|c| &c.ident).collect();
+    let types: Vec<_> = columns.iter().map(|c| &c.ty).collect();
+    let indexes: Vec<_> = (0..columns.len()).collect();
+    let accessors = columns.iter().enumerate().map(|(index, column)| {
+        let Column {
+            ident: field, name, ..
+        } = column;
+        let doc = format!("The field of column `{}`.", name);
+        quote! {
+            #[doc = #doc]
+            pub fn #field() -> ::wcdb::orm::field::Field<#ident> {
+                ::wcdb::orm::field::Field::new(#name, #index)
+            }
+        }
+    });
+    let auto_increment = columns
+        .iter()
+        .enumerate()
+        .find(|(_, c)| c.auto_increment)
+        .map(|(index, column)| {
+            let Column {
+                ident: field,
+                ty,
+                name,
+                ..
+            } = column;
+            // Spanned at the field type, so a non-integer type fails to compile right there.
+            let from_rowid = quote_spanned! {ty.span()=>
+                <#ty as ::wcdb::orm::IntegerCoding>::from_rowid(rowid)
+            };
+            quote! {
+                fn auto_increment_field() -> ::std::option::Option<::wcdb::orm::field::Field<Self>> {
+                    ::std::option::Option::Some(::wcdb::orm::field::Field::new(#name, #index))
+                }
+
+                fn set_last_insert_rowid(&mut self, rowid: i64) {
+                    self.#field = #from_rowid;
+                }
+            }
+        });
+
+    Ok(quote! {
+        impl ::wcdb::orm::TableCoding for #ident {
+            fn binding() -> &'static ::wcdb::orm::binding::TableBinding {
+                static BINDING: ::std::sync::OnceLock<::wcdb::orm::binding::TableBinding> =
+                    ::std::sync::OnceLock::new();
+                BINDING.get_or_init(|| {
+                    let binding = ::wcdb::orm::binding::TableBinding::new();
+                    #(#bindings)*
+                    binding
+                })
+            }
+
+            fn all_fields() -> ::std::vec::Vec<::wcdb::orm::field::Field<Self>> {
+                ::std::vec![#(::wcdb::orm::field::Field::new(#names, #indexes)),*]
+            }
+
+            fn encode(&self, field: &::wcdb::orm::field::Field<Self>) -> ::wcdb::winq::value::Value {
+                match field.index() {
+                    #(#indexes => ::wcdb::orm::ColumnCoding::to_value(&self.#idents),)*
+                    index => unreachable!("no field at index {}", index),
+                }
+            }
+
+            fn decode(
+                stmt: &::wcdb::core::prepared::PreparedStatement,
+                fields: &[::wcdb::orm::field::Field<Self>],
+                offset: i32,
+            ) -> Self {
+                let null = ::wcdb::winq::value::Value::Null;
+                let mut object = #ident {
+                    #(#idents: <#types as ::wcdb::orm::ColumnCoding>::from_value(&null),)*
+                };
+                for (i, field) in fields.iter().enumerate() {
+                    let value = stmt.get_value(offset + i as i32);
+                    match field.index() {
+                        #(#indexes => {
+                            object.#idents =
+                                <#types as ::wcdb::orm::ColumnCoding>::from_value(&value)
+                        })*
+                        index => unreachable!("no field at index {}", index),
+                    }
+                }
+                object
+            }
+
+            #auto_increment
+        }
+
+        impl #ident {
+            #(#accessors)*
+        }
+    })
+}
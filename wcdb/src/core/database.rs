@@ -2,13 +2,18 @@ use either::Either;
 
 use crate::{
     error::{self, Result, WCDBError},
+    orm::{binding::CreateTableReport, multi_select::MultiSelect, table::Table, TableCoding},
     utils::{cpp_bridged, path_to_cstring},
+    winq::{convert::IntoName, statement::Statement, value::Value},
     Tag,
 };
 use std::{ffi::CString, path::Path};
 use std::{ptr, sync::Arc};
 
-use super::handle::Handle;
+use super::{
+    handle::Handle,
+    prepared::{HandlePreparedStatement, PreparedStatement},
+};
 
 cpp_bridged!(struct InnerDatabase(libwcdb_sys::CPPDatabase));
 
@@ -175,6 +180,89 @@ impl Database {
         self.get_handle_with_hint(false)
     }
 
+    /// Prepare a statement on a handle picked by whether the statement writes to the database.
+    /// The write hint comes from `WCDBStatementNeedToWrite`, and is corrected by `sqlite3_stmt_readonly`
+    /// after the statement is prepared.
+    /// The handle is released when the returned statement is dropped.
+    pub fn prepare(&self, stmt: &dyn Statement) -> Result<HandlePreparedStatement> {
+        let need_to_write = unsafe { libwcdb_sys::WCDBStatementNeedToWrite(stmt.raw_stmt()) };
+        let handle = self.get_handle_with_hint(need_to_write)?;
+        let prepared =
+            HandlePreparedStatement::new(handle.get_or_create_prepared_stmt(stmt)?, handle);
+        if need_to_write || prepared.is_read_only() {
+            return Ok(prepared);
+        }
+        drop(prepared);
+        let handle = self.get_handle_with_hint(true)?;
+        let prepared = handle.get_or_create_prepared_stmt(stmt)?;
+        Ok(HandlePreparedStatement::new(prepared, handle))
+    }
+
+    /// Execute a statement on a handle picked by whether the statement writes to the database.
+    pub fn exec(&self, stmt: &dyn Statement) -> Result<()> {
+        self.prepare(stmt)?.step_rows(|_| Ok(true))
+    }
+
+    /// Run a query and call `on_row` with the prepared statement positioned at each row.
+    pub fn query<F>(&self, stmt: &dyn Statement, mut on_row: F) -> Result<()>
+    where
+        F: FnMut(&PreparedStatement) -> Result<()>,
+    {
+        self.prepare(stmt)?.step_rows(|row| {
+            on_row(row)?;
+            Ok(true)
+        })
+    }
+
+    /// Create the table of `T` on a write handle, see `Handle::create_table`.
+    pub fn create_table<T: TableCoding>(&self, table: impl IntoName) -> Result<CreateTableReport> {
+        self.get_handle_with_hint(true)?.create_table::<T>(table)
+    }
+
+    /// The table `name` of the ORM type `T`, which should have been created by `create_table`.
+    pub fn table<T: TableCoding>(&self, name: impl IntoName) -> Result<Table<T>> {
+        Table::new(self, name)
+    }
+
+    /// A query of the rows joined from `table1` of `T1` and `table2` of `T2`,
+    /// which must be different tables.
+    pub fn multi_select<T1: TableCoding, T2: TableCoding>(
+        &self,
+        table1: impl IntoName,
+        table2: impl IntoName,
+    ) -> Result<MultiSelect<T1, T2>> {
+        MultiSelect::new(self, table1, table2)
+    }
+
+    /// Run `transaction` in a transaction on a write handle, see `Handle::run_transaction`.
+    pub fn run_transaction<F, R>(&self, transaction: F) -> Result<R>
+    where
+        F: FnOnce(&Handle) -> Result<R>,
+    {
+        self.get_handle_with_hint(true)?
+            .run_transaction(transaction)
+    }
+
+    /// The value of the first column in the first row, see `Handle::get_value`.
+    pub fn get_value(&self, stmt: &dyn Statement) -> Result<Option<Value>> {
+        Ok(self.get_row(stmt)?.and_then(|row| row.into_iter().next()))
+    }
+
+    /// The values of the first column in all rows, see `Handle::get_column`.
+    pub fn get_column(&self, stmt: &dyn Statement) -> Result<Vec<Value>> {
+        self.prepare(stmt)?.first_column()
+    }
+
+    /// The values of all columns in the first row, see `Handle::get_row`.
+    pub fn get_row(&self, stmt: &dyn Statement) -> Result<Option<Vec<Value>>> {
+        self.prepare(stmt)?.first_row()
+    }
+
+    /// The values of all columns in all rows, see `Handle::get_rows`.
+    pub fn get_rows(&self, stmt: &dyn Statement) -> Result<Vec<Vec<Value>>> {
+        self.prepare(stmt)?.all_rows()
+    }
+
     pub fn error(&self) -> error::Error {
         let err = unsafe { libwcdb_sys::WCDBDatabaseGetError(self.as_ptr()) };
         let wcdb_error = Box::new(WCDBError::from(err));
@@ -416,3 +504,112 @@ impl Database {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        utils::TempDir,
+        winq::{
+            expression_operable::ExpressionOperable,
+            identifiers::column::Column,
+            statements::{insert::StatementInsert, select::StatementSelect},
+        },
+    };
+
+    #[test]
+    fn test_exec_and_query() {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("database.db")).unwrap();
+        database
+            .get_handle_with_hint(true)
+            .unwrap()
+            .exec_sql(c"CREATE TABLE t(a INTEGER)")
+            .unwrap();
+
+        let insert = StatementInsert::new()
+            .insert_into(c"t")
+            .unwrap()
+            .columns([Column::new(c"a").unwrap()])
+            .values([1])
+            .values([2]);
+        database.exec(&insert).unwrap();
+
+        let select = StatementSelect::new()
+            .select([Column::new(c"a").unwrap()])
+            .from(["t"])
+            .unwrap();
+        assert!(database.prepare(&select).unwrap().is_read_only());
+        assert!(!database.prepare(&insert).unwrap().is_read_only());
+
+        let mut values = Vec::new();
+        database
+            .query(&select, |row| {
+                values.push(row.get_value(0).int64());
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(values, vec![1, 2]);
+
+        // The cached statement is reset after a failed query, so the next query starts over.
+        let failed = database.query(&select, |row| row.bind_named(":missing", 1));
+        assert!(matches!(failed, Err(error::Error::UnknownParameter(_))));
+        values.clear();
+        database
+            .query(&select, |row| {
+                values.push(row.get_value(0).int64());
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_get_values() {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("database.db")).unwrap();
+        let handle = database.get_handle_with_hint(true).unwrap();
+        handle
+            .exec_sql(c"CREATE TABLE t(a INTEGER, b TEXT)")
+            .unwrap();
+        handle
+            .exec_sql(c"INSERT INTO t VALUES(1, 'x'), (2, NULL)")
+            .unwrap();
+
+        let select = StatementSelect::new()
+            .select([Column::new(c"a").unwrap(), Column::new(c"b").unwrap()])
+            .from(["t"])
+            .unwrap();
+        let empty = StatementSelect::new()
+            .select([Column::new(c"a").unwrap()])
+            .from(["t"])
+            .unwrap()
+            .r#where(Column::new(c"a").unwrap().gt(2));
+        assert_eq!(
+            database.get_value(&select).unwrap(),
+            Some(Value::Integer64(1))
+        );
+        assert_eq!(database.get_value(&empty).unwrap(), None);
+        assert_eq!(
+            database.get_column(&select).unwrap(),
+            vec![Value::Integer64(1), Value::Integer64(2)]
+        );
+        assert_eq!(
+            handle.get_row(&select).unwrap(),
+            Some(vec![Value::Integer64(1), Value::Text(String::from("x"))])
+        );
+        assert_eq!(handle.get_row(&empty).unwrap(), None);
+        assert_eq!(
+            handle.get_rows(&select).unwrap(),
+            vec![
+                vec![Value::Integer64(1), Value::Text(String::from("x"))],
+                vec![Value::Integer64(2), Value::Null],
+            ]
+        );
+        // The cached statement has been reset, so it can be read again.
+        assert_eq!(
+            handle.get_value(&select).unwrap(),
+            Some(Value::Integer64(1))
+        );
+    }
+}
@@ -1,11 +1,16 @@
-use std::ffi::CStr;
-
 use libwcdb_sys::CPPHandleStatement;
 
 use crate::{
     error::{self, Result, WCDBError},
+    orm::{binding::CreateTableReport, TableCoding},
     utils::cpp_bridged,
-    winq::statement::Statement,
+    winq::{
+        convert::IntoName,
+        identifiers::{column::Column, table_or_subquery::TableOrSubquery},
+        statement::Statement,
+        statements::select::StatementSelect,
+        value::Value,
+    },
 };
 
 use super::{database::Database, prepared::PreparedStatement};
@@ -65,9 +70,10 @@ impl Handle {
     }
 
     /// Execute a raw SQL statement directly.
-    pub fn exec_sql(&self, sql: &CStr) -> Result<()> {
-        let sql = sql.as_ptr();
-        let executed = unsafe { libwcdb_sys::WCDBHandleExecuteSQL(self.as_ptr(), sql) };
+    pub fn exec_sql<S: IntoName>(&self, sql: S) -> Result<()> {
+        let executed = sql.with_name(|sql| unsafe {
+            libwcdb_sys::WCDBHandleExecuteSQL(self.as_ptr(), sql.as_ptr())
+        })?;
         if !executed {
             return Err(self.error());
         }
@@ -128,6 +134,115 @@ impl Handle {
     }
 }
 
+// value
+impl Handle {
+    /// The value of the first column in the first row, or `None` if there is no row.
+    pub fn get_value(&self, stmt: &dyn Statement) -> Result<Option<Value>> {
+        Ok(self.get_row(stmt)?.and_then(|row| row.into_iter().next()))
+    }
+
+    /// The values of the first column in all rows.
+    pub fn get_column(&self, stmt: &dyn Statement) -> Result<Vec<Value>> {
+        self.get_or_create_prepared_stmt(stmt)?.first_column()
+    }
+
+    /// The values of all columns in the first row, or `None` if there is no row.
+    pub fn get_row(&self, stmt: &dyn Statement) -> Result<Option<Vec<Value>>> {
+        self.get_or_create_prepared_stmt(stmt)?.first_row()
+    }
+
+    /// The values of all columns in all rows.
+    pub fn get_rows(&self, stmt: &dyn Statement) -> Result<Vec<Vec<Value>>> {
+        self.get_or_create_prepared_stmt(stmt)?.all_rows()
+    }
+}
+
+// transaction
+impl Handle {
+    pub fn is_in_transaction(&self) -> bool {
+        unsafe { libwcdb_sys::WCDBHandleIsInTransaction(self.as_ptr()) }
+    }
+
+    /// Begin a transaction.
+    pub fn begin_transaction(&self) -> Result<()> {
+        let began = unsafe { libwcdb_sys::WCDBHandleBeginTransaction(self.as_ptr()) };
+        if !began {
+            return Err(self.error());
+        }
+        Ok(())
+    }
+
+    /// Commit the current transaction.
+    pub fn commit_transaction(&self) -> Result<()> {
+        let committed = unsafe { libwcdb_sys::WCDBHandleCommitTransaction(self.as_ptr()) };
+        if !committed {
+            return Err(self.error());
+        }
+        Ok(())
+    }
+
+    /// Roll back the current transaction.
+    pub fn rollback_transaction(&self) {
+        unsafe { libwcdb_sys::WCDBHandleRollbackTransaction(self.as_ptr()) }
+    }
+
+    /// Run `transaction` in a transaction, which is committed if it returns `Ok`
+    /// and rolled back otherwise.
+    /// If the handle is already in a transaction, `transaction` runs as a part of it.
+    pub fn run_transaction<F, R>(&self, transaction: F) -> Result<R>
+    where
+        F: FnOnce(&Handle) -> Result<R>,
+    {
+        if self.is_in_transaction() {
+            return transaction(self);
+        }
+        self.begin_transaction()?;
+        match transaction(self) {
+            Ok(result) => match self.commit_transaction() {
+                Ok(()) => Ok(result),
+                Err(err) => {
+                    self.rollback_transaction();
+                    Err(err)
+                }
+            },
+            Err(err) => {
+                self.rollback_transaction();
+                Err(err)
+            }
+        }
+    }
+}
+
+// table
+impl Handle {
+    /// Whether the table exists.
+    pub fn table_exists<N: IntoName>(&self, table: N) -> Result<bool> {
+        let exists = table.with_name(|table| unsafe {
+            libwcdb_sys::WCDBHandleExistTable(self.as_ptr(), table.as_ptr())
+        })?;
+        if !exists.hasValue {
+            return Err(self.error());
+        }
+        Ok(exists.value)
+    }
+
+    /// The column names of the table, from `pragma_table_info`.
+    pub fn table_columns<N: IntoName>(&self, table: N) -> Result<Vec<String>> {
+        let table = table.with_name(|table| table.to_string_lossy().into_owned())?;
+        let select = StatementSelect::new()
+            .select([Column::new("name")?])
+            .from([TableOrSubquery::function("pragma_table_info")?.argument(table)])?;
+        let columns = self.get_column(&select)?;
+        Ok(columns.iter().map(Value::text).collect())
+    }
+
+    /// Create the table of `T` with its indexes if it does not exist,
+    /// or add the columns and indexes that `T` gained since the table was created.
+    pub fn create_table<T: TableCoding>(&self, table: impl IntoName) -> Result<CreateTableReport> {
+        T::binding().create_table(table, self)
+    }
+}
+
 // main statement
 impl Handle {
     pub fn get_main_stmt(&self) -> CPPHandleStatement {
@@ -1,3 +1,424 @@
-use crate::utils::cpp_bridged;
+use std::{ffi::CString, ops::Deref};
+
+use crate::{
+    error::{Error, Result, WCDBError},
+    utils::{c_ptr_to_string_opt, cpp_bridged},
+    winq::value::Value,
+};
+
+use super::handle::Handle;
 
 cpp_bridged!(pub struct PreparedStatement(libwcdb_sys::CPPHandleStatement));
+
+impl PreparedStatement {
+    pub fn as_ptr(&self) -> libwcdb_sys::CPPHandleStatement {
+        self.0
+    }
+
+    pub fn error(&self) -> Error {
+        let err = unsafe { libwcdb_sys::WCDBHandleStatementGetError(self.as_ptr()) };
+        let wcdb_error = Box::new(WCDBError::from(err));
+        wcdb_error.into()
+    }
+
+    /// The wrapper of `sqlite3_step`.
+    /// Returns `true` if a new row is ready, or `false` if the statement is done.
+    pub fn step(&self) -> Result<bool> {
+        let succeed = unsafe { libwcdb_sys::WCDBHandleStatementStep(self.as_ptr()) };
+        if !succeed {
+            return Err(self.error());
+        }
+        Ok(!self.is_done())
+    }
+
+    /// Whether the statement has no more rows.
+    pub fn is_done(&self) -> bool {
+        unsafe { libwcdb_sys::WCDBHandleStatementIsDone(self.as_ptr()) }
+    }
+
+    /// The wrapper of `sqlite3_reset`.
+    pub fn reset(&self) {
+        unsafe { libwcdb_sys::WCDBHandleStatementReset(self.as_ptr()) }
+    }
+
+    /// The wrapper of `sqlite3_clear_bindings`.
+    pub fn clear_bindings(&self) {
+        unsafe { libwcdb_sys::WCDBHandleStatementClearBindings(self.as_ptr()) }
+    }
+
+    /// The wrapper of `sqlite3_finalize`.
+    pub fn finalize(&self) {
+        unsafe { libwcdb_sys::WCDBHandleStatementFinalize(self.as_ptr()) }
+    }
+
+    /// The wrapper of `sqlite3_stmt_readonly`.
+    pub fn is_read_only(&self) -> bool {
+        unsafe { libwcdb_sys::WCDBHandleStatementIsReadOnly(self.as_ptr()) }
+    }
+}
+
+// column
+impl PreparedStatement {
+    /// The wrapper of `sqlite3_column_count`.
+    pub fn column_count(&self) -> i32 {
+        unsafe { libwcdb_sys::WCDBHandleStatementGetColumnCount(self.as_ptr()) }
+    }
+
+    /// The wrapper of `sqlite3_column_name`.
+    pub fn column_name(&self, index: i32) -> String {
+        let name = unsafe { libwcdb_sys::WCDBHandleStatementGetColumnName(self.as_ptr(), index) };
+        c_ptr_to_string_opt(name).unwrap_or_default()
+    }
+
+    /// Get the value of the column at `index` in the current row, which starts from 0.
+    pub fn get_value(&self, index: i32) -> Value {
+        let stmt = self.as_ptr();
+        let column_type = unsafe { libwcdb_sys::WCDBHandleStatementGetColumnType(stmt, index) };
+        match column_type {
+            libwcdb_sys::WCDBColumnValueType_WCDBColumnValueTypeInterger => {
+                Value::Integer64(unsafe { libwcdb_sys::WCDBHandleStatementGetInteger(stmt, index) })
+            }
+            libwcdb_sys::WCDBColumnValueType_WCDBColumnValueTypeFloat => {
+                Value::Float(unsafe { libwcdb_sys::WCDBHandleStatementGetDouble(stmt, index) })
+            }
+            libwcdb_sys::WCDBColumnValueType_WCDBColumnValueTypeString => {
+                let text = unsafe { libwcdb_sys::WCDBHandleStatementGetText(stmt, index) };
+                Value::Text(c_ptr_to_string_opt(text).unwrap_or_default())
+            }
+            libwcdb_sys::WCDBColumnValueType_WCDBColumnValueTypeBLOB => {
+                let blob = unsafe { libwcdb_sys::WCDBHandleStatementGetBlob(stmt, index) };
+                let size = unsafe { libwcdb_sys::WCDBHandleStatementGetColumnSize(stmt, index) };
+                if blob.is_null() || size <= 0 {
+                    Value::Blob(Vec::new())
+                } else {
+                    Value::Blob(unsafe { std::slice::from_raw_parts(blob, size as _) }.to_vec())
+                }
+            }
+            _ => Value::Null,
+        }
+    }
+
+    /// Get the values of all columns in the current row.
+    pub fn get_values(&self) -> Vec<Value> {
+        (0..self.column_count())
+            .map(|i| self.get_value(i))
+            .collect()
+    }
+}
+
+// rows
+impl PreparedStatement {
+    /// Step through the rows until `on_row` returns `false` or fails, then reset the statement
+    /// so that a cached statement does not hold the read transaction.
+    pub(crate) fn step_rows<F>(&self, mut on_row: F) -> Result<()>
+    where
+        F: FnMut(&PreparedStatement) -> Result<bool>,
+    {
+        let mut stepped = || {
+            while self.step()? {
+                if !on_row(self)? {
+                    break;
+                }
+            }
+            Ok(())
+        };
+        let result = stepped();
+        self.reset();
+        result
+    }
+
+    pub(crate) fn first_row(&self) -> Result<Option<Vec<Value>>> {
+        let mut first = None;
+        self.step_rows(|row| {
+            first = Some(row.get_values());
+            Ok(false)
+        })?;
+        Ok(first)
+    }
+
+    pub(crate) fn first_column(&self) -> Result<Vec<Value>> {
+        let mut column = Vec::new();
+        self.step_rows(|row| {
+            column.push(row.get_value(0));
+            Ok(true)
+        })?;
+        Ok(column)
+    }
+
+    pub(crate) fn all_rows(&self) -> Result<Vec<Vec<Value>>> {
+        let mut rows = Vec::new();
+        self.step_rows(|row| {
+            rows.push(row.get_values());
+            Ok(true)
+        })?;
+        Ok(rows)
+    }
+}
+
+// bind
+impl PreparedStatement {
+    /// Bind a value to the parameter at `index`, which starts from 1.
+    pub fn bind<V: Into<Value>>(&self, index: i32, value: V) -> Result<()> {
+        self.bind_value(index, &value.into())
+    }
+
+    /// Bind a value to the parameter at `index`, which starts from 1.
+    /// Fails if there is no parameter at `index`.
+    pub fn bind_value(&self, index: i32, value: &Value) -> Result<()> {
+        let count = self.bind_parameter_count();
+        if index < 1 || index > count {
+            return Err(Error::ParameterIndexOutOfRange { index, count });
+        }
+        let stmt = self.as_ptr();
+        match value {
+            Value::Null => unsafe { libwcdb_sys::WCDBHandleStatementBindNull(stmt, index) },
+            Value::Integer32(value) => unsafe {
+                libwcdb_sys::WCDBHandleStatementBindInteger(stmt, index, *value as _)
+            },
+            Value::Integer64(value) => unsafe {
+                libwcdb_sys::WCDBHandleStatementBindInteger(stmt, index, *value)
+            },
+            Value::Float(value) => unsafe {
+                libwcdb_sys::WCDBHandleStatementBindDouble(stmt, index, *value)
+            },
+            Value::Text(value) => {
+                let value = CString::new(value.as_str())?;
+                unsafe { libwcdb_sys::WCDBHandleStatementBindText(stmt, index, value.as_ptr()) }
+            }
+            Value::Blob(value) => unsafe {
+                libwcdb_sys::WCDBHandleStatementBindBlob(
+                    stmt,
+                    index,
+                    value.as_ptr(),
+                    value.len() as _,
+                )
+            },
+        }
+        Ok(())
+    }
+
+    /// The wrapper of `sqlite3_bind_parameter_count`, which is the largest parameter index.
+    pub fn bind_parameter_count(&self) -> i32 {
+        unsafe { libwcdb_sys::WCDBRustHandleStatementBindParameterCount(self.as_ptr()) }
+    }
+
+    /// The wrapper of `sqlite3_bind_parameter_index`.
+    /// `name` includes the prefix, e.g. `:id`, `@id`, `$id` or `?1`.
+    pub fn bind_parameter_index(&self, name: &str) -> Result<Option<i32>> {
+        let name = CString::new(name)?;
+        let index = unsafe {
+            libwcdb_sys::WCDBHandleStatementBindParameterIndex(self.as_ptr(), name.as_ptr())
+        };
+        Ok((index > 0).then_some(index))
+    }
+
+    /// Bind a value to the parameter named `name`, e.g. `:id`.
+    pub fn bind_named<V: Into<Value>>(&self, name: &str, value: V) -> Result<()> {
+        let index = self
+            .bind_parameter_index(name)?
+            .ok_or_else(|| Error::UnknownParameter(name.to_string()))?;
+        self.bind(index, value)
+    }
+
+    /// Bind all parameters at once, either by position with a tuple or by name with `params!{}`.
+    ///
+    ///     stmt.bind_all((1, "name"))?;
+    ///     stmt.bind_all(params! { ":id" => 1, ":name" => "name" })?;
+    ///
+    /// Positional values must match the parameters of the statement one to one,
+    /// otherwise `Error::ParameterCountMismatch` is returned and nothing is bound.
+    pub fn bind_all<P: Params>(&self, params: P) -> Result<()> {
+        params.bind_to(self)
+    }
+
+    fn bind_positional(&self, values: Vec<Value>) -> Result<()> {
+        let expected = self.bind_parameter_count();
+        if values.len() != expected as usize {
+            return Err(Error::ParameterCountMismatch {
+                expected,
+                actual: values.len(),
+            });
+        }
+        for (index, value) in values.iter().enumerate() {
+            self.bind_value(index as i32 + 1, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `PreparedStatement` together with the `Handle` it is prepared on,
+/// returned by `Database::prepare`. The statement is reset when dropped,
+/// and the handle is released after it.
+pub struct HandlePreparedStatement {
+    statement: PreparedStatement,
+    handle: Handle,
+}
+
+impl HandlePreparedStatement {
+    pub(crate) fn new(statement: PreparedStatement, handle: Handle) -> Self {
+        Self { statement, handle }
+    }
+
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+}
+
+impl Drop for HandlePreparedStatement {
+    fn drop(&mut self) {
+        self.statement.reset();
+    }
+}
+
+impl Deref for HandlePreparedStatement {
+    type Target = PreparedStatement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.statement
+    }
+}
+
+/// Parameters that can be bound to a `PreparedStatement` at once.
+pub trait Params {
+    fn bind_to(self, stmt: &PreparedStatement) -> Result<()>;
+}
+
+/// Named parameters, usually created by `params!{}`.
+#[derive(Default)]
+pub struct NamedParams<'a>(pub Vec<(&'a str, Value)>);
+
+impl Params for NamedParams<'_> {
+    fn bind_to(self, stmt: &PreparedStatement) -> Result<()> {
+        for (name, value) in self.0 {
+            stmt.bind_named(name, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl Params for Vec<Value> {
+    fn bind_to(self, stmt: &PreparedStatement) -> Result<()> {
+        stmt.bind_positional(self)
+    }
+}
+
+impl Params for () {
+    fn bind_to(self, stmt: &PreparedStatement) -> Result<()> {
+        stmt.bind_positional(Vec::new())
+    }
+}
+
+macro_rules! tuple_params {
+    ($($t:ident),+) => {
+        impl<$($t: Into<Value>),+> Params for ($($t,)+) {
+            #[allow(non_snake_case)]
+            fn bind_to(self, stmt: &PreparedStatement) -> Result<()> {
+                let ($($t,)+) = self;
+                stmt.bind_positional(vec![$($t.into()),+])
+            }
+        }
+    };
+}
+
+tuple_params!(A);
+tuple_params!(A, B);
+tuple_params!(A, B, C);
+tuple_params!(A, B, C, D);
+tuple_params!(A, B, C, D, E);
+tuple_params!(A, B, C, D, E, F);
+tuple_params!(A, B, C, D, E, F, G);
+tuple_params!(A, B, C, D, E, F, G, H);
+tuple_params!(A, B, C, D, E, F, G, H, I);
+tuple_params!(A, B, C, D, E, F, G, H, I, J);
+tuple_params!(A, B, C, D, E, F, G, H, I, J, K);
+tuple_params!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Build `NamedParams` for `PreparedStatement::bind_all`.
+///
+///     params! { ":id" => 1, ":name" => "name" }
+#[macro_export]
+macro_rules! params {
+    () => {
+        $crate::core::prepared::NamedParams::default()
+    };
+    ($($name:expr => $value:expr),+ $(,)?) => {
+        $crate::core::prepared::NamedParams(vec![
+            $(($name, $crate::winq::value::Value::from($value))),+
+        ])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::database::Database,
+        utils::TempDir,
+        winq::{identifiers::bind::BindParameter, statements::select::StatementSelect},
+    };
+
+    #[test]
+    fn test_bind_named() {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("prepared.db")).unwrap();
+        let handle = database.get_handle().unwrap();
+        let select = StatementSelect::new().select([
+            BindParameter::named(c"a").unwrap(),
+            BindParameter::at(c"b").unwrap(),
+            BindParameter::dollar(c"c").unwrap(),
+        ]);
+        let stmt = handle.get_or_create_prepared_stmt(&select).unwrap();
+        stmt.bind_named(":a", 1).unwrap();
+        stmt.bind_named("@b", "b").unwrap();
+        stmt.bind_named("$c", ()).unwrap();
+        assert!(matches!(
+            stmt.bind_named(":d", 1),
+            Err(Error::UnknownParameter(name)) if name == ":d"
+        ));
+        stmt.bind_all(crate::params! { ":a" => 2, "@b" => Some(1.5), "$c" => vec![0u8] })
+            .unwrap();
+        assert!(stmt.bind_all(crate::params! { ":e" => 1 }).is_err());
+        assert!(stmt.step().unwrap());
+    }
+
+    #[test]
+    fn test_bind_all() {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("prepared.db")).unwrap();
+        let handle = database.get_handle().unwrap();
+        let select = StatementSelect::new().select([BindParameter::_1(), BindParameter::_2()]);
+        let stmt = handle.get_or_create_prepared_stmt(&select).unwrap();
+        stmt.bind_all((1, "a")).unwrap();
+        stmt.bind_all(vec![Value::Null, Value::Float(1.0)]).unwrap();
+        assert!(stmt.step().unwrap());
+    }
+
+    #[test]
+    fn test_bind_count_mismatch() {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("prepared.db")).unwrap();
+        let handle = database.get_handle().unwrap();
+        let select = StatementSelect::new().select([BindParameter::_1(), BindParameter::_2()]);
+        let stmt = handle.get_or_create_prepared_stmt(&select).unwrap();
+        assert_eq!(stmt.bind_parameter_count(), 2);
+        assert!(matches!(
+            stmt.bind_all((1, "a", 2.0)),
+            Err(Error::ParameterCountMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+        assert!(matches!(
+            stmt.bind_all((1,)),
+            Err(Error::ParameterCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+        assert!(matches!(
+            stmt.bind(3, 1),
+            Err(Error::ParameterIndexOutOfRange { index: 3, count: 2 })
+        ));
+        assert!(stmt.bind(0, 1).is_err());
+    }
+}
@@ -13,6 +13,16 @@ pub enum Error {
     NulError(#[from] NulError),
     #[error("WCDB internal error: {0}")]
     WCDBError(#[from] Box<WCDBError>),
+    #[error("No parameter named {0} in the statement")]
+    UnknownParameter(String),
+    #[error("No parameter at index {index}, the statement has {count} parameters")]
+    ParameterIndexOutOfRange { index: i32, count: i32 },
+    #[error("The statement has {expected} parameters, but {actual} values are given")]
+    ParameterCountMismatch { expected: i32, actual: usize },
+    #[error("An update needs at least one field to set")]
+    EmptyUpdate,
+    #[error("Table {0} can not be joined with itself, whose columns would be ambiguous")]
+    SelfJoin(String),
 }
 
 #[derive(Debug, Eq, PartialEq, TryFromPrimitive)]
@@ -1,5 +1,9 @@
+// Lets `#[derive(TableCoding)]` refer to `::wcdb` inside this crate.
+extern crate self as wcdb;
+
 pub mod core;
 pub mod error;
+pub mod orm;
 pub(crate) mod utils;
 pub mod winq;
 
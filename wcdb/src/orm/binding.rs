@@ -0,0 +1,403 @@
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_void,
+};
+
+use crate::{
+    core::handle::Handle,
+    error::Result,
+    utils::cpp_bridged,
+    winq::{
+        convert::IntoName,
+        identifier::WithRawIdentifier,
+        identifiers::{
+            column::Column, column_constraint::ColumnConstraint, column_def::ColumnDef,
+            table_constraint::TableConstraint,
+        },
+        statements::{create_index::StatementCreateIndex, insert::StatementInsert},
+        types::ColumnType,
+    },
+};
+
+cpp_bridged!(struct InnerBinding(libwcdb_sys::CPPBinding));
+
+// The binding is only configured while it is being built, and read afterwards.
+unsafe impl Send for InnerBinding {}
+unsafe impl Sync for InnerBinding {}
+
+/// The schema of an ORM table: its columns, indexes and constraints.
+///
+/// A binding is declared once and reused for creating tables and generating statements.
+pub struct TableBinding {
+    inner: InnerBinding,
+    columns: Vec<CString>,
+    is_virtual: bool,
+}
+
+impl TableBinding {
+    pub fn new() -> TableBinding {
+        let raw = unsafe { libwcdb_sys::WCDBBindingCreate() };
+        TableBinding {
+            inner: raw.into(),
+            columns: Vec::new(),
+            is_virtual: false,
+        }
+    }
+
+    pub fn as_ptr(&self) -> libwcdb_sys::CPPBinding {
+        self.inner.0
+    }
+
+    /// The pointer used by `Column::new_with_table_binding`.
+    pub fn base_binding(&self) -> *const c_void {
+        unsafe { libwcdb_sys::WCDBBindingGetBaseBinding(self.as_ptr()) }
+    }
+
+    /// Declare a column as `name column_type`.
+    pub fn column_def<N: IntoName>(mut self, name: N, column_type: ColumnType) -> Result<Self> {
+        let name = name.with_name(CStr::to_owned)?;
+        let def = ColumnDef::named_with_type(name.as_c_str(), column_type)?;
+        def.with_raw(|d| unsafe { libwcdb_sys::WCDBBindingAddColumnDef(self.as_ptr(), d) });
+        self.columns.push(name);
+        Ok(self)
+    }
+
+    /// Add a constraint to a declared column.
+    pub fn column_constraint<N: IntoName>(
+        self,
+        column: N,
+        constraint: ColumnConstraint,
+    ) -> Result<Self> {
+        column.with_name(|column| {
+            constraint.with_raw(|c| unsafe {
+                libwcdb_sys::WCDBBindingAddColumnConstraint(self.as_ptr(), column.as_ptr(), c)
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// Add an index named `table_name + suffix`.
+    /// The table name of `create_index` is filled in when the table is created.
+    pub fn index<N: IntoName>(self, suffix: N, create_index: StatementCreateIndex) -> Result<Self> {
+        self.index_inner(suffix, false, create_index)
+    }
+
+    /// Add an index named `name`, regardless of the table name.
+    pub fn index_with_full_name<N: IntoName>(
+        self,
+        name: N,
+        create_index: StatementCreateIndex,
+    ) -> Result<Self> {
+        self.index_inner(name, true, create_index)
+    }
+
+    fn index_inner<N: IntoName>(
+        self,
+        name: N,
+        is_full_name: bool,
+        create_index: StatementCreateIndex,
+    ) -> Result<Self> {
+        name.with_name(|name| {
+            create_index.with_raw(|c| unsafe {
+                libwcdb_sys::WCDBBindingAddIndex(self.as_ptr(), name.as_ptr(), is_full_name, c)
+            })
+        })?;
+        Ok(self)
+    }
+
+    pub fn table_constraint(self, constraint: TableConstraint) -> Self {
+        constraint
+            .with_raw(|c| unsafe { libwcdb_sys::WCDBBindingAddTableConstraint(self.as_ptr(), c) });
+        self
+    }
+
+    /// `WITHOUT ROWID`
+    pub fn without_row_id(self) -> Self {
+        unsafe { libwcdb_sys::WCDBBindingConfigWithoutRowId(self.as_ptr()) };
+        self
+    }
+
+    /// Make the integer primary key of an existing table behave as `AUTOINCREMENT`
+    /// without rebuilding it, so that the rowids of deleted rows are never reused.
+    pub fn enable_auto_increment_for_existing_table(self) -> Self {
+        unsafe { libwcdb_sys::WCDBBindingEnableAutoIncrementForExistingTable(self.as_ptr()) };
+        self
+    }
+
+    /// `USING module`, which makes the table a virtual table.
+    pub fn virtual_module<N: IntoName>(mut self, module: N) -> Result<Self> {
+        module.with_name(|module| unsafe {
+            libwcdb_sys::WCDBBindingConfigVirtualModule(self.as_ptr(), module.as_ptr())
+        })?;
+        self.is_virtual = true;
+        Ok(self)
+    }
+
+    /// Append an argument of the virtual table module, e.g. `tokenize = 'unicode61'`.
+    pub fn virtual_module_argument<N: IntoName>(self, argument: N) -> Result<Self> {
+        argument.with_name(|argument| unsafe {
+            libwcdb_sys::WCDBBindingConfigVirtualModuleArgument(self.as_ptr(), argument.as_ptr())
+        })?;
+        Ok(self)
+    }
+
+    /// The argument `tokenize = 'tokenizer'` of a full-text search module,
+    /// e.g. `unicode61 remove_diacritics 2`, quoted as a string literal.
+    pub fn tokenizer<N: IntoName>(self, tokenizer: N) -> Result<Self> {
+        let argument = tokenizer
+            .with_name(|t| format!("tokenize = '{}'", t.to_string_lossy().replace('\'', "''")))?;
+        self.virtual_module_argument(argument)
+    }
+
+    pub fn is_virtual(&self) -> bool {
+        self.is_virtual
+    }
+
+    pub fn is_primary_key<N: IntoName>(&self, column: N) -> Result<bool> {
+        column.with_name(|column| unsafe {
+            libwcdb_sys::WCDBBindingIsPrimaryKey(self.as_ptr(), column.as_ptr())
+        })
+    }
+
+    /// The declared columns, in declaration order.
+    pub fn columns(&self) -> Vec<Column> {
+        self.columns
+            .iter()
+            .map(|name| self.column_inner(name))
+            .collect()
+    }
+
+    /// The declared column named `name`.
+    pub fn column<N: IntoName>(&self, name: N) -> Result<Option<Column>> {
+        name.with_name(|name| {
+            self.columns
+                .iter()
+                .find(|c| c.as_c_str() == name)
+                .map(|name| self.column_inner(name))
+        })
+    }
+
+    fn column_inner(&self, name: &CStr) -> Column {
+        // The name has been checked when it was declared.
+        Column::new_with_table_binding(name, self.base_binding()).unwrap()
+    }
+
+    /// `INSERT INTO table(column, ...) VALUES(?1, ...)` of all declared columns.
+    pub fn insert<N: IntoName>(&self, table: N) -> Result<StatementInsert> {
+        Ok(StatementInsert::new()
+            .insert_into(table)?
+            .columns(self.columns())
+            .values_with_bind_parameters(self.columns.len()))
+    }
+
+    /// Create the table with its indexes, or add the missing columns and indexes
+    /// if the table exists.
+    ///
+    /// A virtual table is created by `create_virtual_table`, and never gets columns added.
+    pub fn create_table<N: IntoName>(
+        &self,
+        table: N,
+        handle: &Handle,
+    ) -> Result<CreateTableReport> {
+        let table = table.with_name(CStr::to_owned)?;
+        let existing = if handle.table_exists(table.as_c_str())? {
+            Some(handle.table_columns(table.as_c_str())?)
+        } else {
+            None
+        };
+        if self.is_virtual {
+            self.create_virtual_table(table.as_c_str(), handle)?;
+            return Ok(CreateTableReport {
+                created: existing.is_none(),
+                added_columns: Vec::new(),
+            });
+        }
+        let created = unsafe {
+            libwcdb_sys::WCDBBindingCreateTable(self.as_ptr(), table.as_ptr(), handle.as_ptr())
+        };
+        if !created {
+            return Err(handle.error());
+        }
+        let report = match existing {
+            Some(existing) => CreateTableReport {
+                created: false,
+                added_columns: self
+                    .columns
+                    .iter()
+                    .map(|c| c.to_string_lossy().into_owned())
+                    .filter(|c| !existing.iter().any(|e| e.eq_ignore_ascii_case(c)))
+                    .collect(),
+            },
+            None => CreateTableReport {
+                created: true,
+                added_columns: Vec::new(),
+            },
+        };
+        Ok(report)
+    }
+
+    /// Create the virtual table configured by `virtual_module`.
+    pub fn create_virtual_table<N: IntoName>(&self, table: N, handle: &Handle) -> Result<()> {
+        let created = table.with_name(|table| unsafe {
+            libwcdb_sys::WCDBBindingCreateVirtualTable(
+                self.as_ptr(),
+                table.as_ptr(),
+                handle.as_ptr(),
+            )
+        })?;
+        if !created {
+            return Err(handle.error());
+        }
+        Ok(())
+    }
+}
+
+/// What `TableBinding::create_table` changed in the database.
+///
+/// Only columns are reported. The missing indexes are created too, but not listed here.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CreateTableReport {
+    /// Whether the table did not exist and has been created.
+    pub created: bool,
+    /// The columns added to an existing table, in declaration order.
+    pub added_columns: Vec<String>,
+}
+
+impl Default for TableBinding {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::database::Database,
+        utils::TempDir,
+        winq::{
+            expression_operable::ExpressionOperable, identifiers::indexed_column::IndexedColumn,
+            statements::select::StatementSelect,
+        },
+    };
+
+    fn binding() -> Result<TableBinding> {
+        TableBinding::new()
+            .column_def("id", ColumnType::Integer64)?
+            .column_constraint("id", ColumnConstraint::new().primary_key().auto_increment())?
+            .column_def("chat_id", ColumnType::Integer64)?
+            .column_def("body", ColumnType::Text)?
+            .index(
+                "_chat_id_index",
+                StatementCreateIndex::new()
+                    .indexed_by([IndexedColumn::new(Column::new("chat_id")?)]),
+            )
+    }
+
+    #[test]
+    fn test_binding() -> Result<()> {
+        let binding = binding()?;
+        assert!(binding.is_primary_key("id")?);
+        assert!(!binding.is_primary_key("body")?);
+        assert_eq!(binding.columns().len(), 3);
+        assert!(binding.column("chat_id")?.is_some());
+        assert!(binding.column("missing")?.is_none());
+        assert_eq!(
+            binding.insert("message")?.description(),
+            "INSERT INTO message(id, chat_id, body) VALUES(?1, ?2, ?3)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_table() -> Result<()> {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("binding.db"))?;
+        let binding = binding()?;
+        let report = binding.create_table("message", &database.get_handle_with_hint(true)?)?;
+        assert!(report.created);
+
+        let insert = binding.insert("message")?;
+        let handle = database.get_handle_with_hint(true)?;
+        let stmt = handle.get_or_create_prepared_stmt(&insert)?;
+        stmt.bind_all((1, 10, "hello"))?;
+        stmt.step()?;
+
+        let select = StatementSelect::new()
+            .select(binding.columns())
+            .from(["message"])?
+            .r#where(Column::new("chat_id")?.eq(10));
+        let mut bodies = Vec::new();
+        database.query(&select, |row| {
+            bodies.push(row.get_value(2).text());
+            Ok(())
+        })?;
+        assert_eq!(bodies, vec![String::from("hello")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_table_add_column() -> Result<()> {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("binding.db"))?;
+        let handle = database.get_handle_with_hint(true)?;
+        binding()?.create_table("message", &handle)?;
+
+        let binding = binding()?
+            .column_def("title", ColumnType::Text)?
+            .column_def("read", ColumnType::Integer32)?;
+        let report = binding.create_table("message", &handle)?;
+        assert_eq!(
+            report,
+            CreateTableReport {
+                created: false,
+                added_columns: vec![String::from("title"), String::from("read")],
+            }
+        );
+        assert_eq!(
+            handle.table_columns("message")?,
+            vec!["id", "chat_id", "body", "title", "read"]
+        );
+        assert_eq!(
+            binding.create_table("message", &handle)?,
+            CreateTableReport::default()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_auto_increment_for_existing_table() -> Result<()> {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("binding.db"))?;
+        let handle = database.get_handle_with_hint(true)?;
+        let new_binding = |constraint: ColumnConstraint| -> Result<TableBinding> {
+            TableBinding::new()
+                .column_def("id", ColumnType::Integer64)?
+                .column_constraint("id", constraint)?
+                .column_def("body", ColumnType::Text)
+        };
+        // A table created before its primary key was declared as `AUTOINCREMENT`.
+        let binding = new_binding(ColumnConstraint::new().primary_key())?;
+        binding.create_table("message", &handle)?;
+        let insert = |binding: &TableBinding, body: &str| -> Result<i64> {
+            let stmt = handle.get_or_create_prepared_stmt(&binding.insert("message")?)?;
+            stmt.reset();
+            stmt.bind_all(((), body))?;
+            stmt.step()?;
+            Ok(handle.last_insert_rowid())
+        };
+
+        // Without AUTOINCREMENT, the rowid of the last row is reused after it is deleted.
+        assert_eq!(insert(&binding, "a")?, 1);
+        assert_eq!(insert(&binding, "b")?, 2);
+        handle.exec_sql("DELETE FROM message WHERE id = 2")?;
+        assert_eq!(insert(&binding, "c")?, 2);
+
+        let binding = new_binding(ColumnConstraint::new().primary_key().auto_increment())?
+            .enable_auto_increment_for_existing_table();
+        binding.create_table("message", &handle)?;
+        handle.exec_sql("DELETE FROM message WHERE id = 2")?;
+        assert_eq!(insert(&binding, "d")?, 3);
+        Ok(())
+    }
+}
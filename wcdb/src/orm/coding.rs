@@ -0,0 +1,418 @@
+use crate::{
+    core::prepared::PreparedStatement,
+    winq::{types::ColumnType, value::Value},
+};
+
+use super::{binding::TableBinding, field::Field};
+
+/// A Rust type that can be stored in a single column.
+///
+/// Integers are stored as 64-bit integers. `u64` and `usize` above `i64::MAX` are stored as
+/// negative integers and decoded back unchanged, while a stored integer out of the range of a
+/// narrower type is truncated when decoded, like `as`.
+pub trait ColumnCoding: Sized {
+    /// The declared type of the column.
+    fn column_type() -> ColumnType;
+
+    fn to_value(&self) -> Value;
+
+    /// Decode the value of the column. `Value::Null` decodes to the zero value of the type.
+    fn from_value(value: &Value) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty => $column_type:ident),*) => {
+        $(
+            impl ColumnCoding for $t {
+                fn column_type() -> ColumnType {
+                    ColumnType::$column_type
+                }
+
+                fn to_value(&self) -> Value {
+                    Value::Integer64(*self as _)
+                }
+
+                fn from_value(value: &Value) -> Self {
+                    value.int64() as _
+                }
+            }
+
+            impl IntegerCoding for $t {
+                fn from_rowid(rowid: i64) -> Self {
+                    rowid as _
+                }
+            }
+        )*
+    };
+}
+
+integer!(
+    i8 => Integer32,
+    i16 => Integer32,
+    i32 => Integer32,
+    i64 => Integer64,
+    u8 => Integer32,
+    u16 => Integer32,
+    u32 => Integer64,
+    u64 => Integer64,
+    isize => Integer64,
+    usize => Integer64
+);
+
+/// An integer type, which is required by `#[wcdb(auto_increment)]` to hold the assigned rowid.
+pub trait IntegerCoding: ColumnCoding {
+    fn from_rowid(rowid: i64) -> Self;
+}
+
+impl<T: IntegerCoding> IntegerCoding for Option<T> {
+    fn from_rowid(rowid: i64) -> Self {
+        Some(T::from_rowid(rowid))
+    }
+}
+
+impl ColumnCoding for bool {
+    fn column_type() -> ColumnType {
+        ColumnType::Integer32
+    }
+
+    fn to_value(&self) -> Value {
+        Value::from(*self)
+    }
+
+    fn from_value(value: &Value) -> Self {
+        value.int64() != 0
+    }
+}
+
+impl ColumnCoding for f32 {
+    fn column_type() -> ColumnType {
+        ColumnType::Float
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Float(*self as _)
+    }
+
+    fn from_value(value: &Value) -> Self {
+        value.float() as _
+    }
+}
+
+impl ColumnCoding for f64 {
+    fn column_type() -> ColumnType {
+        ColumnType::Float
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Float(*self)
+    }
+
+    fn from_value(value: &Value) -> Self {
+        value.float()
+    }
+}
+
+impl ColumnCoding for String {
+    fn column_type() -> ColumnType {
+        ColumnType::Text
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Text(self.clone())
+    }
+
+    fn from_value(value: &Value) -> Self {
+        value.text()
+    }
+}
+
+impl ColumnCoding for Vec<u8> {
+    fn column_type() -> ColumnType {
+        ColumnType::Blob
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Blob(self.clone())
+    }
+
+    fn from_value(value: &Value) -> Self {
+        value.blob()
+    }
+}
+
+/// `None` is stored as `NULL`.
+impl<T: ColumnCoding> ColumnCoding for Option<T> {
+    fn column_type() -> ColumnType {
+        T::column_type()
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            Some(value) => value.to_value(),
+            None => Value::Null,
+        }
+    }
+
+    fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Null => None,
+            value => Some(T::from_value(value)),
+        }
+    }
+}
+
+/// A Rust type mapped to a table, usually implemented by `#[derive(TableCoding)]`.
+///
+/// ```ignore
+/// #[derive(TableCoding)]
+/// struct Message {
+///     #[wcdb(primary, auto_increment)]
+///     id: i64,
+///     #[wcdb(index)]
+///     chat_id: i64,
+///     body: String,
+/// }
+/// ```
+///
+/// The field attributes are:
+/// - `primary`: `PRIMARY KEY`.
+/// - `auto_increment`: `AUTOINCREMENT` of the integer primary key, whose zero or `None` value is assigned by SQLite.
+/// - `unique`: `UNIQUE`.
+/// - `not_null`: `NOT NULL`.
+/// - `index`: an index named `table_name + "_" + column_name + "_index"`.
+/// - `column_name = "name"`: the column name, which defaults to the field name.
+/// - `unindexed`: `UNINDEXED` column of a full-text search table.
+///
+/// Each field gets an accessor `T::field()`, so a field can not be named after an item of this
+/// trait, such as `binding` or `encode`. Rename it and keep the column name with `column_name`.
+///
+/// `#[wcdb(auto_increment_for_existing_table)]` on the struct makes the integer primary key of
+/// an existing table behave as `AUTOINCREMENT`, see `TableBinding::enable_auto_increment_for_existing_table`.
+///
+/// A full-text search table is declared on the struct, and is created as a virtual table:
+///
+/// ```ignore
+/// #[derive(TableCoding)]
+/// #[wcdb(virtual_table(module = "fts5", tokenizer = "unicode61"))]
+/// struct Document {
+///     #[wcdb(unindexed)]
+///     id: i64,
+///     content: String,
+/// }
+/// ```
+pub trait TableCoding: Sized {
+    /// The binding shared by all tables of this type.
+    fn binding() -> &'static TableBinding;
+
+    /// All fields, in declaration order.
+    fn all_fields() -> Vec<Field<Self>>;
+
+    /// Encode the value of `field` for binding to a statement.
+    fn encode(&self, field: &Field<Self>) -> Value;
+
+    /// Decode a row whose columns, starting at `offset`, are `fields`.
+    /// Fields not in `fields` are left as zero values.
+    fn decode(stmt: &PreparedStatement, fields: &[Field<Self>], offset: i32) -> Self;
+
+    /// The auto-increment primary key.
+    fn auto_increment_field() -> Option<Field<Self>> {
+        None
+    }
+
+    /// Whether the auto-increment primary key is zero or `None`, which is left for SQLite to assign.
+    fn is_auto_increment(&self) -> bool {
+        Self::auto_increment_field().is_some_and(|field| {
+            matches!(
+                self.encode(&field),
+                Value::Null | Value::Integer32(0) | Value::Integer64(0)
+            )
+        })
+    }
+
+    /// Write back the rowid assigned to an auto-increment primary key.
+    fn set_last_insert_rowid(&mut self, _rowid: i64) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::database::Database,
+        error::Result,
+        orm::TableCoding,
+        utils::TempDir,
+        winq::{
+            expression_operable::ExpressionOperable, identifiers::column::Column,
+            statements::select::StatementSelect,
+        },
+    };
+
+    #[derive(TableCoding, Debug, PartialEq)]
+    struct Message {
+        #[wcdb(primary, auto_increment)]
+        id: i64,
+        #[wcdb(index)]
+        chat_id: i64,
+        body: String,
+        #[wcdb(column_name = "reply_to")]
+        reply: Option<i64>,
+    }
+
+    #[test]
+    fn test_binding() -> Result<()> {
+        let binding = Message::binding();
+        assert!(binding.is_primary_key("id")?);
+        assert!(!binding.is_primary_key("chat_id")?);
+        assert_eq!(Message::reply().name(), "reply_to");
+        assert_eq!(Message::body().index(), 2);
+        assert_eq!(Message::all_fields().len(), 4);
+        assert_eq!(Message::auto_increment_field(), Some(Message::id()));
+        assert_eq!(Message::chat_id().eq(1).description(), "chat_id == 1");
+        assert_eq!(
+            binding.insert("message")?.description(),
+            "INSERT INTO message(id, chat_id, body, reply_to) VALUES(?1, ?2, ?3, ?4)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_integer_coding() {
+        assert_eq!(u64::from_value(&u64::MAX.to_value()), u64::MAX);
+        assert_eq!(usize::from_value(&usize::MAX.to_value()), usize::MAX);
+        assert_eq!(isize::from_value(&isize::MIN.to_value()), isize::MIN);
+        assert_eq!(i8::from_value(&Value::Integer64(300)), 44);
+        assert!(matches!(u64::column_type(), ColumnType::Integer64));
+        assert_eq!(Option::<u64>::from_rowid(1), Some(1));
+    }
+
+    #[test]
+    fn test_encode_and_decode() -> Result<()> {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("coding.db"))?;
+        let handle = database.get_handle_with_hint(true)?;
+        assert!(handle.create_table::<Message>("message")?.created);
+
+        let mut message = Message {
+            id: 0,
+            chat_id: 1,
+            body: String::from("hello"),
+            reply: None,
+        };
+        assert!(message.is_auto_increment());
+        let stmt = handle.get_or_create_prepared_stmt(&Message::binding().insert("message")?)?;
+        let values = Message::all_fields()
+            .iter()
+            .map(|field| match field.index() {
+                0 => Value::Null,
+                _ => message.encode(field),
+            })
+            .collect::<Vec<_>>();
+        stmt.bind_all(values)?;
+        stmt.step()?;
+        message.set_last_insert_rowid(handle.last_insert_rowid());
+        assert_eq!(message.id, 1);
+        assert!(!message.is_auto_increment());
+
+        let fields = [Message::id(), Message::body()];
+        let select = StatementSelect::new().select(fields).from(["message"])?;
+        let mut messages = Vec::new();
+        database.query(&select, |row| {
+            messages.push(Message::decode(row, &fields, 0));
+            Ok(())
+        })?;
+        assert_eq!(
+            messages,
+            vec![Message {
+                id: 1,
+                chat_id: 0,
+                body: String::from("hello"),
+                reply: None,
+            }]
+        );
+        Ok(())
+    }
+
+    #[derive(TableCoding, Debug, PartialEq)]
+    #[wcdb(virtual_table(module = "fts5", tokenizer = "unicode61 remove_diacritics 2"))]
+    struct Document {
+        #[wcdb(unindexed)]
+        id: i64,
+        content: String,
+    }
+
+    #[test]
+    fn test_virtual_table() -> Result<()> {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("coding.db"))?;
+        assert!(Document::binding().is_virtual());
+        assert!(database.create_table::<Document>("document")?.created);
+        assert!(!database.create_table::<Document>("document")?.created);
+
+        let table = database.table::<Document>("document")?;
+        table.insert_objects(&mut [
+            Document {
+                id: 1,
+                content: String::from("hello world"),
+            },
+            Document {
+                id: 2,
+                content: String::from("goodbye world"),
+            },
+        ])?;
+        let documents = table
+            .select()
+            .r#where(Document::content().r#match("hello"))
+            .get_objects()?;
+        assert_eq!(
+            documents,
+            vec![Document {
+                id: 1,
+                content: String::from("hello world"),
+            }]
+        );
+        // `id` is stored but not indexed, so matching the whole table does not find it.
+        assert!(table
+            .select()
+            .r#where(Column::new("document")?.r#match("1"))
+            .get_objects()?
+            .is_empty());
+        Ok(())
+    }
+
+    #[derive(TableCoding)]
+    #[wcdb(auto_increment_for_existing_table)]
+    struct Legacy {
+        #[wcdb(primary, auto_increment)]
+        id: i64,
+        body: String,
+    }
+
+    #[test]
+    fn test_auto_increment_for_existing_table() -> Result<()> {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("coding.db"))?;
+        // A table created before its primary key was declared as `AUTOINCREMENT`.
+        database
+            .get_handle_with_hint(true)?
+            .exec_sql("CREATE TABLE legacy(id INTEGER PRIMARY KEY, body TEXT)")?;
+        database.create_table::<Legacy>("legacy")?;
+
+        let table = database.table::<Legacy>("legacy")?;
+        let mut legacies = (0..2)
+            .map(|_| Legacy {
+                id: 0,
+                body: String::new(),
+            })
+            .collect::<Vec<_>>();
+        table.insert_objects(&mut legacies)?;
+        assert_eq!(legacies[1].id, 2);
+        table.delete().r#where(Legacy::id().eq(2)).execute()?;
+        let mut legacy = Legacy {
+            id: 0,
+            body: String::new(),
+        };
+        table.insert_object(&mut legacy)?;
+        assert_eq!(legacy.id, 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use std::marker::PhantomData;
+
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsColumn, AsExpression, AsSchema, IntoName, NonTextExpression},
+        expression_operable::ExpressionOperable,
+        identifiers::{column::Column, expression::Expression},
+    },
+};
+
+use super::coding::TableCoding;
+
+/// A column of the ORM type `T`, generated by `#[derive(TableCoding)]` as `T::field_name()`.
+///
+/// It can be used wherever a column or an expression is expected.
+pub struct Field<T> {
+    name: &'static str,
+    index: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Field<T> {
+    /// The field at `index` in declaration order, stored in the column `name`.
+    /// Only for `#[derive(TableCoding)]`, whose `encode` and `decode` expect a declared index.
+    #[doc(hidden)]
+    pub fn new(name: &'static str, index: usize) -> Field<T> {
+        Field {
+            name,
+            index,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<T: TableCoding> Field<T> {
+    /// The column bound to the table binding of `T`.
+    pub fn column(&self) -> Column {
+        // The name has been checked when the binding was declared.
+        Column::new_with_table_binding(self.name, T::binding().base_binding()).unwrap()
+    }
+}
+
+impl<T> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Field<T> {}
+
+impl<T> PartialEq for Field<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T> std::fmt::Debug for Field<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Field")
+            .field("name", &self.name)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<T: TableCoding> AsExpression for Field<T> {
+    fn as_expression(self) -> Expression {
+        self.column().as_expression()
+    }
+}
+
+impl<T: TableCoding> NonTextExpression for Field<T> {}
+
+impl<T: TableCoding> AsColumn for Field<T> {
+    fn as_column(self) -> Column {
+        self.column()
+    }
+
+    fn r#in<N: IntoName>(self, name: N) -> Result<Column> {
+        self.column().r#in(name)
+    }
+
+    fn of<S: AsSchema>(self, schema: S) -> Result<Column> {
+        self.column().of(schema)
+    }
+}
+
+impl<T: TableCoding> ExpressionOperable for Field<T> {}
@@ -0,0 +1,21 @@
+/// Applies the `limit` and `offset` kept by a query to its statement, so that an `offset` on its
+/// own still takes effect.
+macro_rules! limit_offset {
+    ($statement:expr, $limit:expr, $offset:expr) => {
+        match ($limit, $offset) {
+            (Some(limit), Some(offset)) => $statement.limit(limit).offset(offset).into_statement(),
+            (Some(limit), None) => $statement.limit(limit).into_statement(),
+            (None, Some(offset)) => $statement.offset(offset).into_statement(),
+            (None, None) => $statement,
+        }
+    };
+}
+
+pub mod binding;
+pub mod coding;
+pub mod field;
+pub mod multi_select;
+pub mod table;
+
+pub use coding::{ColumnCoding, IntegerCoding, TableCoding};
+pub use wcdb_derive::TableCoding;
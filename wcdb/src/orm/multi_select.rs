@@ -0,0 +1,216 @@
+use std::marker::PhantomData;
+
+use crate::{
+    core::database::Database,
+    error::{Error, Result},
+    winq::{
+        convert::{AsColumn, AsExpression, AsOrderingTerm, IntoName},
+        identifiers::{column::Column, expression::Expression},
+        statements::select::StatementSelect,
+    },
+};
+
+use super::coding::TableCoding;
+
+/// A query of the rows joined from a table of `T1` and a table of `T2`,
+/// each decoded into a pair of objects. Created by `Database::multi_select`.
+///
+/// The result columns are qualified by their table names, so the conditions should be too:
+///
+///     database
+///         .multi_select::<Message, Contact>("message", "contact")?
+///         .r#where(Message::sender().r#in("message")?.eq(Contact::id().r#in("contact")?))
+///         .get_objects()?;
+pub struct MultiSelect<T1, T2> {
+    database: Database,
+    tables: (String, String),
+    statement: StatementSelect,
+    limit: Option<Expression>,
+    offset: Option<Expression>,
+    _marker: PhantomData<fn() -> (T1, T2)>,
+}
+
+impl<T1: TableCoding, T2: TableCoding> MultiSelect<T1, T2> {
+    /// Fails with `Error::SelfJoin` if both names are the same table,
+    /// which SQLite compares case-insensitively.
+    pub fn new<N1: IntoName, N2: IntoName>(
+        database: &Database,
+        table1: N1,
+        table2: N2,
+    ) -> Result<MultiSelect<T1, T2>> {
+        let table1 = table1.with_name(|name| name.to_string_lossy().into_owned())?;
+        let table2 = table2.with_name(|name| name.to_string_lossy().into_owned())?;
+        if table1.eq_ignore_ascii_case(&table2) {
+            return Err(Error::SelfJoin(table1));
+        }
+        Ok(MultiSelect {
+            database: database.clone(),
+            tables: (table1, table2),
+            statement: StatementSelect::new(),
+            limit: None,
+            offset: None,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn r#where<E: AsExpression>(mut self, condition: E) -> Self {
+        self.statement = self.statement.r#where(condition);
+        self
+    }
+
+    pub fn order_by<O, I>(mut self, orders: I) -> Self
+    where
+        O: AsOrderingTerm,
+        I: IntoIterator<Item = O>,
+    {
+        self.statement = self.statement.order_by(orders).into_statement();
+        self
+    }
+
+    pub fn limit<E: AsExpression>(mut self, limit: E) -> Self {
+        self.limit = Some(limit.as_expression());
+        self
+    }
+
+    pub fn offset<E: AsExpression>(mut self, offset: E) -> Self {
+        self.offset = Some(offset.as_expression());
+        self
+    }
+
+    pub fn get_objects(self) -> Result<Vec<(T1, T2)>> {
+        let (table1, table2) = &self.tables;
+        let fields1 = T1::all_fields();
+        let fields2 = T2::all_fields();
+        let columns = fields1
+            .iter()
+            .map(|field| field.r#in(table1.as_str()))
+            .chain(fields2.iter().map(|field| field.r#in(table2.as_str())))
+            .collect::<Result<Vec<Column>>>()?;
+        let statement = self
+            .statement
+            .select(columns)
+            .from([table1.as_str(), table2.as_str()])?;
+        let statement: StatementSelect = limit_offset!(statement, self.limit, self.offset);
+        let offset = fields1.len() as i32;
+        let mut objects = Vec::new();
+        self.database.query(&statement, |row| {
+            objects.push((
+                T1::decode(row, &fields1, 0),
+                T2::decode(row, &fields2, offset),
+            ));
+            Ok(())
+        })?;
+        Ok(objects)
+    }
+
+    /// The first pair, which overrides the limit to 1.
+    pub fn get_object(self) -> Result<Option<(T1, T2)>> {
+        Ok(self.limit(1).get_objects()?.into_iter().next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        orm::TableCoding,
+        utils::TempDir,
+        winq::{expression_operable::ExpressionOperable, types::Order},
+    };
+
+    #[derive(TableCoding, Debug, PartialEq)]
+    struct Message {
+        #[wcdb(primary)]
+        id: i64,
+        sender: i64,
+        body: String,
+    }
+
+    #[derive(TableCoding, Debug, PartialEq)]
+    struct Contact {
+        #[wcdb(primary)]
+        id: i64,
+        name: String,
+    }
+
+    #[test]
+    fn test_multi_select() -> Result<()> {
+        let dir = TempDir::new();
+        let database = Database::create(dir.path("multi-select.db"))?;
+        database.create_table::<Message>("message")?;
+        database.create_table::<Contact>("contact")?;
+        database.table::<Message>("message")?.insert_objects(&mut [
+            Message {
+                id: 1,
+                sender: 10,
+                body: String::from("a"),
+            },
+            Message {
+                id: 2,
+                sender: 20,
+                body: String::from("b"),
+            },
+        ])?;
+        database.table::<Contact>("contact")?.insert_objects(&mut [
+            Contact {
+                id: 10,
+                name: String::from("x"),
+            },
+            Contact {
+                id: 20,
+                name: String::from("y"),
+            },
+        ])?;
+
+        let select = database
+            .multi_select::<Message, Contact>("message", "contact")?
+            .r#where(
+                Message::sender()
+                    .r#in("message")?
+                    .eq(Contact::id().r#in("contact")?),
+            )
+            .order_by([Message::id().r#in("message")?.order(Order::Desc)]);
+        let pairs = select.get_objects()?;
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(
+            pairs[0],
+            (
+                Message {
+                    id: 2,
+                    sender: 20,
+                    body: String::from("b"),
+                },
+                Contact {
+                    id: 20,
+                    name: String::from("y"),
+                }
+            )
+        );
+        assert_eq!(pairs[1].1.name, "x");
+
+        let pair = database
+            .multi_select::<Message, Contact>("message", "contact")?
+            .r#where(Contact::name().r#in("contact")?.eq("x"))
+            .get_object()?;
+        assert_eq!(pair.map(|(_, contact)| contact.id), Some(10));
+
+        let pairs = database
+            .multi_select::<Message, Contact>("message", "contact")?
+            .r#where(
+                Message::sender()
+                    .r#in("message")?
+                    .eq(Contact::id().r#in("contact")?),
+            )
+            .order_by([Message::id().r#in("message")?])
+            .offset(1)
+            .get_objects()?;
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].0.id, 2);
+
+        assert!(matches!(
+            database.multi_select::<Message, Message>("message", "MESSAGE"),
+            Err(Error::SelfJoin(name)) if name == "message"
+        ));
+        Ok(())
+    }
+}
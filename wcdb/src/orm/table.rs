@@ -0,0 +1,595 @@
+use std::marker::PhantomData;
+
+use crate::{
+    core::database::Database,
+    error::{Error, Result},
+    winq::{
+        convert::{AsExpression, AsOrderingTerm, IntoName},
+        identifiers::{bind::BindParameter, expression::Expression},
+        statements::{
+            delete::StatementDelete, insert::StatementInsert, select::StatementSelect,
+            update::StatementUpdate,
+        },
+        types::Conflict,
+        value::Value,
+    },
+};
+
+use super::{coding::TableCoding, field::Field};
+
+/// A table of the ORM type `T`, created by `Database::table`.
+pub struct Table<T> {
+    database: Database,
+    name: String,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: TableCoding> Table<T> {
+    pub fn new<N: IntoName>(database: &Database, name: N) -> Result<Table<T>> {
+        let name = name.with_name(|name| name.to_string_lossy().into_owned())?;
+        Ok(Table {
+            database: database.clone(),
+            name,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn database(&self) -> &Database {
+        &self.database
+    }
+
+    /// Start an insertion, which can be configured before the objects are inserted.
+    ///
+    ///     table.insert().or_replace().fields([Message::body()]).objects(&mut messages)?;
+    pub fn insert(&self) -> Insert<'_, T> {
+        Insert {
+            table: self,
+            conflict: None,
+            fields: None,
+        }
+    }
+
+    pub fn insert_object(&self, object: &mut T) -> Result<()> {
+        self.insert().object(object)
+    }
+
+    pub fn insert_objects(&self, objects: &mut [T]) -> Result<()> {
+        self.insert().objects(objects)
+    }
+
+    /// `INSERT OR REPLACE`
+    pub fn insert_or_replace_objects(&self, objects: &mut [T]) -> Result<()> {
+        self.insert().or_replace().objects(objects)
+    }
+
+    /// `INSERT OR IGNORE`
+    pub fn insert_or_ignore_objects(&self, objects: &mut [T]) -> Result<()> {
+        self.insert().or_ignore().objects(objects)
+    }
+
+    /// Start a query, which can be configured before the objects are read.
+    ///
+    ///     table
+    ///         .select()
+    ///         .r#where(Message::chat_id().eq(1))
+    ///         .order_by([Message::id().order(Order::Desc)])
+    ///         .limit(10)
+    ///         .get_objects()?;
+    pub fn select(&self) -> Select<'_, T> {
+        Select {
+            table: self,
+            statement: StatementSelect::new(),
+            fields: None,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    /// All objects in the table.
+    pub fn get_objects(&self) -> Result<Vec<T>> {
+        self.select().get_objects()
+    }
+
+    /// The first object in the table.
+    pub fn get_object(&self) -> Result<Option<T>> {
+        self.select().get_object()
+    }
+
+    /// Start an update of the rows to the values of `object`.
+    ///
+    ///     table.update(&message).fields([Message::body()]).r#where(Message::id().eq(1)).execute()?;
+    pub fn update<'a>(&'a self, object: &'a T) -> Update<'a, T> {
+        Update {
+            table: self,
+            object,
+            statement: StatementUpdate::new(),
+            fields: None,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    /// Start a deletion of rows.
+    ///
+    ///     table.delete().r#where(Message::chat_id().eq(1)).execute()?;
+    pub fn delete(&self) -> Delete<'_, T> {
+        Delete {
+            table: self,
+            statement: StatementDelete::new(),
+            limit: None,
+            offset: None,
+        }
+    }
+}
+
+impl<T> Clone for Table<T> {
+    fn clone(&self) -> Self {
+        Table {
+            database: self.database.clone(),
+            name: self.name.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// An insertion of objects into a `Table`.
+///
+/// All objects are inserted by one prepared statement inside a transaction.
+/// The auto-increment primary key of an object is assigned by SQLite if it is zero or `None`,
+/// and written back to the object after it is inserted.
+pub struct Insert<'a, T> {
+    table: &'a Table<T>,
+    conflict: Option<Conflict>,
+    fields: Option<Vec<Field<T>>>,
+}
+
+impl<T: TableCoding> Insert<'_, T> {
+    /// `INSERT OR REPLACE`
+    pub fn or_replace(mut self) -> Self {
+        self.conflict = Some(Conflict::Replace);
+        self
+    }
+
+    /// `INSERT OR IGNORE`
+    pub fn or_ignore(mut self) -> Self {
+        self.conflict = Some(Conflict::Ignore);
+        self
+    }
+
+    /// Insert only `fields`, leaving other columns to their defaults.
+    /// Without the auto-increment key among them, the assigned key is always written back.
+    pub fn fields<I: IntoIterator<Item = Field<T>>>(mut self, fields: I) -> Self {
+        self.fields = Some(fields.into_iter().collect());
+        self
+    }
+
+    pub fn object(self, object: &mut T) -> Result<()> {
+        self.objects(std::slice::from_mut(object))
+    }
+
+    pub fn objects(self, objects: &mut [T]) -> Result<()> {
+        if objects.is_empty() {
+            return Ok(());
+        }
+        let fields = self.fields.unwrap_or_else(T::all_fields);
+        let mut insert = StatementInsert::new()
+            .insert_into(self.table.name())?
+            .columns(fields.iter().copied())
+            .values_with_bind_parameters(fields.len());
+        if let Some(conflict) = self.conflict {
+            insert = insert.conflict(conflict);
+        }
+        let auto_increment = T::auto_increment_field();
+        // Without the key among the inserted fields, SQLite assigns it whatever the object holds.
+        let is_key_omitted = auto_increment.is_some_and(|field| !fields.contains(&field));
+
+        let handle = self.table.database.get_handle_with_hint(true)?;
+        let rowids = handle.run_transaction(|handle| {
+            let stmt = handle.get_or_create_prepared_stmt(&insert)?;
+            let mut rowids = Vec::new();
+            for (index, object) in objects.iter().enumerate() {
+                let is_auto_increment = object.is_auto_increment();
+                let values = fields
+                    .iter()
+                    .map(|field| match auto_increment {
+                        Some(auto_increment) if is_auto_increment && *field == auto_increment => {
+                            Value::Null
+                        }
+                        _ => object.encode(field),
+                    })
+                    .collect::<Vec<_>>();
+                stmt.reset();
+                stmt.bind_all(values)?;
+                let stepped = stmt.step();
+                stmt.reset();
+                stepped?;
+                if (is_auto_increment || is_key_omitted) && handle.changes() > 0 {
+                    rowids.push((index, handle.last_insert_rowid()));
+                }
+            }
+            Ok(rowids)
+        })?;
+        // Written back only after the commit, so a rolled back batch leaves the objects untouched.
+        for (index, rowid) in rowids {
+            objects[index].set_last_insert_rowid(rowid);
+        }
+        Ok(())
+    }
+}
+
+/// A query of objects from a `Table`.
+pub struct Select<'a, T> {
+    table: &'a Table<T>,
+    statement: StatementSelect,
+    fields: Option<Vec<Field<T>>>,
+    limit: Option<Expression>,
+    offset: Option<Expression>,
+}
+
+impl<T: TableCoding> Select<'_, T> {
+    pub fn r#where<E: AsExpression>(mut self, condition: E) -> Self {
+        self.statement = self.statement.r#where(condition);
+        self
+    }
+
+    pub fn order_by<O, I>(mut self, orders: I) -> Self
+    where
+        O: AsOrderingTerm,
+        I: IntoIterator<Item = O>,
+    {
+        self.statement = self.statement.order_by(orders).into_statement();
+        self
+    }
+
+    pub fn limit<E: AsExpression>(mut self, limit: E) -> Self {
+        self.limit = Some(limit.as_expression());
+        self
+    }
+
+    pub fn offset<E: AsExpression>(mut self, offset: E) -> Self {
+        self.offset = Some(offset.as_expression());
+        self
+    }
+
+    /// Read only `fields`, leaving other fields of the objects as zero values.
+    pub fn fields<I: IntoIterator<Item = Field<T>>>(mut self, fields: I) -> Self {
+        self.fields = Some(fields.into_iter().collect());
+        self
+    }
+
+    pub fn get_objects(self) -> Result<Vec<T>> {
+        let fields = self.fields.unwrap_or_else(T::all_fields);
+        let statement = self
+            .statement
+            .select(fields.iter().copied())
+            .from([self.table.name()])?;
+        let statement: StatementSelect = limit_offset!(statement, self.limit, self.offset);
+        let mut objects = Vec::new();
+        self.table.database.query(&statement, |row| {
+            objects.push(T::decode(row, &fields, 0));
+            Ok(())
+        })?;
+        Ok(objects)
+    }
+
+    /// The first object, which overrides the limit to 1.
+    pub fn get_object(self) -> Result<Option<T>> {
+        Ok(self.limit(1).get_objects()?.into_iter().next())
+    }
+}
+
+/// An update of rows in a `Table` to the values of an object.
+/// Without `r#where`, all rows are updated.
+pub struct Update<'a, T> {
+    table: &'a Table<T>,
+    object: &'a T,
+    statement: StatementUpdate,
+    fields: Option<Vec<Field<T>>>,
+    limit: Option<Expression>,
+    offset: Option<Expression>,
+}
+
+impl<T: TableCoding> Update<'_, T> {
+    /// Update only `fields`.
+    pub fn fields<I: IntoIterator<Item = Field<T>>>(mut self, fields: I) -> Self {
+        self.fields = Some(fields.into_iter().collect());
+        self
+    }
+
+    pub fn r#where<E: AsExpression>(mut self, condition: E) -> Self {
+        self.statement = self.statement.r#where(condition);
+        self
+    }
+
+    pub fn order_by<O, I>(mut self, orders: I) -> Self
+    where
+        O: AsOrderingTerm,
+        I: IntoIterator<Item = O>,
+    {
+        self.statement = self.statement.order_by(orders);
+        self
+    }
+
+    pub fn limit<E: AsExpression>(mut self, limit: E) -> Self {
+        self.limit = Some(limit.as_expression());
+        self
+    }
+
+    pub fn offset<E: AsExpression>(mut self, offset: E) -> Self {
+        self.offset = Some(offset.as_expression());
+        self
+    }
+
+    /// Run the update and return the number of updated rows.
+    /// Fails with `Error::EmptyUpdate` if there is no field to set.
+    pub fn execute(self) -> Result<i32> {
+        let fields = self.fields.unwrap_or_else(T::all_fields);
+        if fields.is_empty() {
+            return Err(Error::EmptyUpdate);
+        }
+        let mut statement = self.statement.update(self.table.name())?;
+        for (i, field) in fields.iter().enumerate() {
+            statement = statement.set([*field]).to(BindParameter::new(i as i32 + 1));
+        }
+        let statement: StatementUpdate = limit_offset!(statement, self.limit, self.offset);
+        let values = fields
+            .iter()
+            .map(|field| self.object.encode(field))
+            .collect::<Vec<_>>();
+        let stmt = self.table.database.prepare(&statement)?;
+        stmt.bind_all(values)?;
+        stmt.step_rows(|_| Ok(true))?;
+        Ok(stmt.handle().changes())
+    }
+}
+
+/// A deletion of rows from a `Table`.
+/// Without `r#where`, all rows are deleted.
+pub struct Delete<'a, T> {
+    table: &'a Table<T>,
+    statement: StatementDelete,
+    limit: Option<Expression>,
+    offset: Option<Expression>,
+}
+
+impl<T: TableCoding> Delete<'_, T> {
+    pub fn r#where<E: AsExpression>(mut self, condition: E) -> Self {
+        self.statement = self.statement.r#where(condition);
+        self
+    }
+
+    pub fn order_by<O, I>(mut self, orders: I) -> Self
+    where
+        O: AsOrderingTerm,
+        I: IntoIterator<Item = O>,
+    {
+        self.statement = self.statement.order_by(orders);
+        self
+    }
+
+    pub fn limit<E: AsExpression>(mut self, limit: E) -> Self {
+        self.limit = Some(limit.as_expression());
+        self
+    }
+
+    pub fn offset<E: AsExpression>(mut self, offset: E) -> Self {
+        self.offset = Some(offset.as_expression());
+        self
+    }
+
+    /// Run the deletion and return the number of deleted rows.
+    pub fn execute(self) -> Result<i32> {
+        let statement = self.statement.delete_from(self.table.name())?;
+        let statement: StatementDelete = limit_offset!(statement, self.limit, self.offset);
+        let stmt = self.table.database.prepare(&statement)?;
+        stmt.step_rows(|_| Ok(true))?;
+        Ok(stmt.handle().changes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        orm::TableCoding,
+        utils::TempDir,
+        winq::{expression_operable::ExpressionOperable, types::Order},
+    };
+
+    #[derive(TableCoding, Debug, Clone, PartialEq)]
+    struct Message {
+        #[wcdb(primary, auto_increment)]
+        id: i64,
+        chat_id: i64,
+        body: Option<String>,
+    }
+
+    fn message(id: i64, body: &str) -> Message {
+        Message {
+            id,
+            chat_id: 1,
+            body: Some(body.to_string()),
+        }
+    }
+
+    fn table(dir: &TempDir) -> Result<Table<Message>> {
+        let database = Database::create(dir.path("table.db"))?;
+        database.create_table::<Message>("message")?;
+        database.table::<Message>("message")
+    }
+
+    #[test]
+    fn test_insert() -> Result<()> {
+        let dir = TempDir::new();
+        let table = table(&dir)?;
+        let mut first = message(0, "a");
+        table.insert_object(&mut first)?;
+        assert_eq!(first.id, 1);
+
+        let mut messages = vec![message(0, "b"), message(10, "c"), message(0, "d")];
+        table.insert_objects(&mut messages)?;
+        assert_eq!(
+            messages.iter().map(|m| m.id).collect::<Vec<_>>(),
+            vec![2, 10, 11]
+        );
+        assert_eq!(table.get_objects()?.len(), 4);
+
+        // The batch is rolled back as a whole, and so are the written back ids.
+        let mut conflicted = vec![message(0, "e"), message(1, "f")];
+        assert!(table.insert_objects(&mut conflicted).is_err());
+        assert_eq!(conflicted[0].id, 0);
+        assert_eq!(table.get_objects()?.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_on_conflict() -> Result<()> {
+        let dir = TempDir::new();
+        let table = table(&dir)?;
+        table.insert_objects(&mut [message(1, "a"), message(2, "b")])?;
+        table.insert_or_ignore_objects(&mut [message(1, "ignored"), message(3, "c")])?;
+        table.insert_or_replace_objects(&mut [message(2, "replaced")])?;
+        assert_eq!(
+            table.get_objects()?,
+            vec![message(1, "a"), message(2, "replaced"), message(3, "c")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_fields() -> Result<()> {
+        let dir = TempDir::new();
+        let table = table(&dir)?;
+        let mut object = message(0, "a");
+        table
+            .insert()
+            .fields([Message::id(), Message::chat_id()])
+            .object(&mut object)?;
+        assert_eq!(object.id, 1);
+        assert_eq!(
+            table.get_objects()?,
+            vec![Message {
+                id: 1,
+                chat_id: 1,
+                body: None,
+            }]
+        );
+
+        let mut object = message(7, "b");
+        table
+            .insert()
+            .fields([Message::chat_id(), Message::body()])
+            .object(&mut object)?;
+        assert_eq!(object.id, 2);
+        assert_eq!(table.get_objects()?[1], message(2, "b"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_select() -> Result<()> {
+        let dir = TempDir::new();
+        let table = table(&dir)?;
+        let mut messages = (1..=5)
+            .map(|id| Message {
+                id,
+                chat_id: id % 2,
+                body: Some(format!("{}", id)),
+            })
+            .collect::<Vec<_>>();
+        table.insert_objects(&mut messages)?;
+
+        let ids = |objects: Vec<Message>| objects.iter().map(|m| m.id).collect::<Vec<_>>();
+        assert_eq!(ids(table.get_objects()?), vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            ids(table
+                .select()
+                .r#where(Message::chat_id().eq(1))
+                .order_by([Message::id().order(Order::Desc)])
+                .get_objects()?),
+            vec![5, 3, 1]
+        );
+        assert_eq!(
+            ids(table.select().limit(2).offset(1).get_objects()?),
+            vec![2, 3]
+        );
+        assert_eq!(ids(table.select().offset(3).get_objects()?), vec![4, 5]);
+        assert_eq!(
+            ids(table.select().offset(1).limit(1).get_objects()?),
+            vec![2]
+        );
+        assert_eq!(
+            table
+                .select()
+                .fields([Message::body()])
+                .r#where(Message::id().eq(4))
+                .get_object()?,
+            Some(Message {
+                id: 0,
+                chat_id: 0,
+                body: Some(String::from("4")),
+            })
+        );
+        assert_eq!(
+            table.select().r#where(Message::id().gt(5)).get_object()?,
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_and_delete() -> Result<()> {
+        let dir = TempDir::new();
+        let table = table(&dir)?;
+        let mut messages = (1..=5).map(|id| message(id, "a")).collect::<Vec<_>>();
+        table.insert_objects(&mut messages)?;
+
+        let updated = Message {
+            id: 0,
+            chat_id: 2,
+            body: Some(String::from("b")),
+        };
+        assert_eq!(
+            table
+                .update(&updated)
+                .fields([Message::body()])
+                .r#where(Message::id().le(2))
+                .execute()?,
+            2
+        );
+        assert_eq!(
+            table
+                .update(&updated)
+                .fields([Message::chat_id(), Message::body()])
+                .r#where(Message::id().eq(5))
+                .execute()?,
+            1
+        );
+        assert!(matches!(
+            table.update(&updated).fields([]).execute(),
+            Err(Error::EmptyUpdate)
+        ));
+        let objects = table.get_objects()?;
+        assert_eq!(objects[1], message(2, "b"));
+        assert_eq!(objects[2], message(3, "a"));
+        assert_eq!(objects[4], Message { id: 5, ..updated });
+
+        assert_eq!(
+            table
+                .delete()
+                .r#where(Message::chat_id().eq(1))
+                .order_by([Message::id().order(Order::Desc)])
+                .limit(2)
+                .execute()?,
+            2
+        );
+        let ids = |objects: Vec<Message>| objects.iter().map(|m| m.id).collect::<Vec<_>>();
+        assert_eq!(ids(table.get_objects()?), vec![1, 2, 5]);
+        assert_eq!(table.delete().execute()?, 3);
+        assert_eq!(table.get_object()?, None);
+        Ok(())
+    }
+}
@@ -1,6 +1,11 @@
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
+
+use libwcdb_sys::CPPStatementSelect;
+
+use crate::error::Result;
 
 use super::{
+    identifier::WithRawIdentifier,
     identifiers::{
         column::Column, column_def::ColumnDef, expression::Expression,
         indexed_column::IndexedColumn, literal_value::LiteralValue, ordering_term::OrderingTerm,
@@ -10,10 +15,74 @@ use super::{
     types::{ColumnType, Order},
 };
 
+/// Names of tables, columns, aliases, collations and so on,
+/// which can be given as `&str`, `String` or `&CStr`.
+pub trait IntoName {
+    /// Call `f` with the name as a C string.
+    /// Fails with `Error::NulError` if the name contains an interior NUL byte.
+    fn with_name<F, R>(self, f: F) -> Result<R>
+    where
+        F: FnOnce(&CStr) -> R;
+}
+
+impl IntoName for &CStr {
+    fn with_name<F, R>(self, f: F) -> Result<R>
+    where
+        F: FnOnce(&CStr) -> R,
+    {
+        Ok(f(self))
+    }
+}
+
+impl IntoName for CString {
+    fn with_name<F, R>(self, f: F) -> Result<R>
+    where
+        F: FnOnce(&CStr) -> R,
+    {
+        Ok(f(&self))
+    }
+}
+
+impl IntoName for &CString {
+    fn with_name<F, R>(self, f: F) -> Result<R>
+    where
+        F: FnOnce(&CStr) -> R,
+    {
+        Ok(f(self))
+    }
+}
+
+impl IntoName for &str {
+    fn with_name<F, R>(self, f: F) -> Result<R>
+    where
+        F: FnOnce(&CStr) -> R,
+    {
+        Ok(f(&CString::new(self)?))
+    }
+}
+
+impl IntoName for String {
+    fn with_name<F, R>(self, f: F) -> Result<R>
+    where
+        F: FnOnce(&CStr) -> R,
+    {
+        Ok(f(&CString::new(self)?))
+    }
+}
+
+impl IntoName for &String {
+    fn with_name<F, R>(self, f: F) -> Result<R>
+    where
+        F: FnOnce(&CStr) -> R,
+    {
+        self.as_str().with_name(f)
+    }
+}
+
 pub trait AsOrderingTerm {
     fn as_order(self) -> OrderingTerm;
     fn order(self, order: Order) -> OrderingTerm;
-    fn collate(self, collation_name: &CStr) -> OrderingTerm;
+    fn collate<N: IntoName>(self, collation_name: N) -> Result<OrderingTerm>;
 }
 
 pub trait AsColumnDef {
@@ -32,30 +101,40 @@ pub trait AsResultColumn {
     fn as_result_column(self) -> ResultColumn;
 }
 
+/// Fails with `Error::NulError` if converted from a name with an interior NUL byte.
 pub trait AsSchema {
-    fn as_schema(self) -> Schema;
+    fn as_schema(self) -> Result<Schema>;
 }
 
+/// Fails with `Error::NulError` if converted from a name with an interior NUL byte.
 pub trait AsQualifiedTable {
-    fn as_qualified_table(self) -> QualifiedTable;
+    fn as_qualified_table(self) -> Result<QualifiedTable>;
 }
 
+/// Fails with `Error::NulError` if converted from a name with an interior NUL byte.
 pub trait AsTableOrSubquery {
-    fn as_table_or_subquery(self) -> TableOrSubquery;
+    fn as_table_or_subquery(self) -> Result<TableOrSubquery>;
 }
 
 pub trait AsExpression {
     fn as_expression(self) -> Expression;
 }
 
-// todo: impl AsResultColumn/AsOrder for AsExpression
+/// Expressions which are ordering terms, result columns and indexed columns by themselves.
+/// Rust strings are left out: there they name a column rather than a text literal,
+/// and panic on an interior NUL byte. Use `LiteralValue::text` for a text literal.
+pub trait NonTextExpression: AsExpression {}
+
+/// Selects which can be used as a subquery, whether compounded, ordered or limited.
+pub trait AsSelect: WithRawIdentifier<CPPStatementSelect> {}
 
 pub trait AsColumn {
     fn as_column(self) -> Column;
-    fn r#in(self, table: &CStr) -> Column;
-    fn of<T: AsSchema>(self, schema: T) -> Column;
+    fn r#in<N: IntoName>(self, table: N) -> Result<Column>;
+    fn of<T: AsSchema>(self, schema: T) -> Result<Column>;
 }
 
+/// Fails with `Error::NulError` if converted from text with an interior NUL byte.
 pub trait AsLiteralValue {
-    fn as_literal_value(self) -> LiteralValue;
+    fn as_literal_value(self) -> Result<LiteralValue>;
 }
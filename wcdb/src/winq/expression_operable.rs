@@ -0,0 +1,353 @@
+use std::ffi::CStr;
+
+use libwcdb_sys::*;
+
+use crate::error::Result;
+
+use super::{
+    convert::{AsExpression, AsSelect, IntoName},
+    identifier::WithRawIdentifier,
+    identifiers::expression::Expression,
+    types::ColumnType,
+};
+
+fn unary(operand: Expression, operator: UnaryOperator) -> Expression {
+    let ptr = operand.with_raw(|o| unsafe { WCDBExpressionUnaryOperate(o, operator.c()) });
+    Expression::from_raw(ptr)
+}
+
+fn null(operand: Expression, is_not: bool) -> Expression {
+    let ptr = operand.with_raw(|o| unsafe { WCDBExpressionNullOperate(o, is_not) });
+    Expression::from_raw(ptr)
+}
+
+fn binary(
+    left: Expression,
+    right: Expression,
+    operator: BinaryOperator,
+    is_not: bool,
+) -> Expression {
+    let ptr = (&left, &right)
+        .with_raw(|(l, r)| unsafe { WCDBExpressionBinaryOperate(l, r, operator.c(), is_not) });
+    Expression::from_raw(ptr)
+}
+
+fn between(operand: Expression, left: Expression, right: Expression, is_not: bool) -> Expression {
+    let ptr = (&operand, (&left, &right))
+        .with_raw(|(o, (l, r))| unsafe { WCDBExpressionBetweenOperate(o, l, r, is_not) });
+    Expression::from_raw(ptr)
+}
+
+fn in_expressions(operand: Expression, expressions: Vec<Expression>, is_not: bool) -> Expression {
+    let ptr = (&operand, &expressions).with_raw(|(o, e)| unsafe {
+        WCDBExpressionInExpressionOperate(o, e.as_ptr(), e.len() as _, is_not)
+    });
+    Expression::from_raw(ptr)
+}
+
+fn in_select<S: AsSelect>(operand: Expression, select: S, is_not: bool) -> Expression {
+    let ptr = (&operand, &select)
+        .with_raw(|(o, s)| unsafe { WCDBExpressionInSelectionOperate(o, s, is_not) });
+    Expression::from_raw(ptr)
+}
+
+fn in_table(operand: Expression, table: &CStr, is_not: bool) -> Expression {
+    let ptr =
+        operand.with_raw(|o| unsafe { WCDBExpressionInTableOperate(o, table.as_ptr(), is_not) });
+    Expression::from_raw(ptr)
+}
+
+fn in_function(operand: Expression, function: &CStr, is_not: bool) -> Expression {
+    let ptr = operand
+        .with_raw(|o| unsafe { WCDBExpressionInFunctionOperate(o, function.as_ptr(), is_not) });
+    Expression::from_raw(ptr)
+}
+
+macro_rules! binary {
+    ($name:ident = $op:ident) => {
+        binary!($name = $op, false);
+    };
+    ($name:ident = $op:ident, $is_not:literal) => {
+        fn $name<T: AsExpression>(self, operand: T) -> Expression {
+            binary(
+                self.as_expression(),
+                operand.as_expression(),
+                BinaryOperator::$op,
+                $is_not,
+            )
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+enum UnaryOperator {
+    Negative,
+    Positive,
+    Tilde,
+    Not,
+}
+
+impl UnaryOperator {
+    fn c(&self) -> WCDBExpressionUnaryOperateType {
+        match self {
+            UnaryOperator::Negative => {
+                WCDBExpressionUnaryOperateType_WCDBExpressionUnaryOperateType_Negative
+            }
+            UnaryOperator::Positive => {
+                WCDBExpressionUnaryOperateType_WCDBExpressionUnaryOperateType_Positive
+            }
+            UnaryOperator::Tilde => {
+                WCDBExpressionUnaryOperateType_WCDBExpressionUnaryOperateType_Tilde
+            }
+            UnaryOperator::Not => WCDBExpressionUnaryOperateType_WCDBExpressionUnaryOperateType_Not,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BinaryOperator {
+    Concatenate,
+    Multiply,
+    Divide,
+    Modulo,
+    Plus,
+    Minus,
+    LeftShift,
+    RightShift,
+    BitwiseAnd,
+    BitwiseOr,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+    Is,
+    And,
+    Or,
+    Like,
+    Glob,
+    RegExp,
+    Match,
+}
+
+impl BinaryOperator {
+    fn c(&self) -> WCDBExpressionBinaryOperatorType {
+        match self {
+            BinaryOperator::Concatenate => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Concatenate
+            }
+            BinaryOperator::Multiply => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Multiply
+            }
+            BinaryOperator::Divide => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Divide
+            }
+            BinaryOperator::Modulo => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Modulo
+            }
+            BinaryOperator::Plus => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Plus
+            }
+            BinaryOperator::Minus => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Minus
+            }
+            BinaryOperator::LeftShift => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_LeftShift
+            }
+            BinaryOperator::RightShift => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_RightShift
+            }
+            BinaryOperator::BitwiseAnd => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_BitwiseAnd
+            }
+            BinaryOperator::BitwiseOr => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_BitwiseOr
+            }
+            BinaryOperator::Less => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Less
+            }
+            BinaryOperator::LessOrEqual => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_LessOrEqual
+            }
+            BinaryOperator::Greater => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Greater
+            }
+            BinaryOperator::GreaterOrEqual => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_GreaterOrEqual
+            }
+            BinaryOperator::Equal => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Equal
+            }
+            BinaryOperator::NotEqual => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_NotEqual
+            }
+            BinaryOperator::Is => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Is
+            }
+            BinaryOperator::And => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_And
+            }
+            BinaryOperator::Or => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Or
+            }
+            BinaryOperator::Like => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Like
+            }
+            BinaryOperator::Glob => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_GLOB
+            }
+            BinaryOperator::RegExp => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_RegExp
+            }
+            BinaryOperator::Match => {
+                WCDBExpressionBinaryOperatorType_WCDBExpressionBinaryOperatorType_Match
+            }
+        }
+    }
+}
+
+/// Operators shared by everything that can be used as an expression operand.
+pub trait ExpressionOperable: AsExpression + Sized {
+    fn negative(self) -> Expression {
+        unary(self.as_expression(), UnaryOperator::Negative)
+    }
+
+    fn positive(self) -> Expression {
+        unary(self.as_expression(), UnaryOperator::Positive)
+    }
+
+    fn tilde(self) -> Expression {
+        unary(self.as_expression(), UnaryOperator::Tilde)
+    }
+
+    fn not(self) -> Expression {
+        unary(self.as_expression(), UnaryOperator::Not)
+    }
+
+    fn is_null(self) -> Expression {
+        null(self.as_expression(), false)
+    }
+
+    fn not_null(self) -> Expression {
+        null(self.as_expression(), true)
+    }
+
+    binary!(concat = Concatenate);
+    binary!(mul = Multiply);
+    binary!(div = Divide);
+    binary!(rem = Modulo);
+    binary!(add = Plus);
+    binary!(sub = Minus);
+    binary!(left_shift = LeftShift);
+    binary!(right_shift = RightShift);
+    binary!(bit_and = BitwiseAnd);
+    binary!(bit_or = BitwiseOr);
+    binary!(lt = Less);
+    binary!(le = LessOrEqual);
+    binary!(gt = Greater);
+    binary!(ge = GreaterOrEqual);
+    binary!(eq = Equal);
+    binary!(ne = NotEqual);
+    binary!(is = Is);
+    binary!(is_not = Is, true);
+    binary!(and = And);
+    binary!(or = Or);
+    binary!(like = Like);
+    binary!(not_like = Like, true);
+    binary!(glob = Glob);
+    binary!(not_glob = Glob, true);
+    binary!(regexp = RegExp);
+    binary!(not_regexp = RegExp, true);
+    binary!(r#match = Match);
+    binary!(not_match = Match, true);
+
+    fn between<T1: AsExpression, T2: AsExpression>(self, begin: T1, end: T2) -> Expression {
+        between(
+            self.as_expression(),
+            begin.as_expression(),
+            end.as_expression(),
+            false,
+        )
+    }
+
+    fn not_between<T1: AsExpression, T2: AsExpression>(self, begin: T1, end: T2) -> Expression {
+        between(
+            self.as_expression(),
+            begin.as_expression(),
+            end.as_expression(),
+            true,
+        )
+    }
+
+    /// `self IN (value, ...)`
+    fn in_values<T, I>(self, values: I) -> Expression
+    where
+        T: AsExpression,
+        I: IntoIterator<Item = T>,
+    {
+        let values = values.into_iter().map(|v| v.as_expression()).collect();
+        in_expressions(self.as_expression(), values, false)
+    }
+
+    /// `self NOT IN (value, ...)`
+    fn not_in_values<T, I>(self, values: I) -> Expression
+    where
+        T: AsExpression,
+        I: IntoIterator<Item = T>,
+    {
+        let values = values.into_iter().map(|v| v.as_expression()).collect();
+        in_expressions(self.as_expression(), values, true)
+    }
+
+    /// `self IN (select)`
+    fn in_select<S: AsSelect>(self, select: S) -> Expression {
+        in_select(self.as_expression(), select, false)
+    }
+
+    /// `self NOT IN (select)`
+    fn not_in_select<S: AsSelect>(self, select: S) -> Expression {
+        in_select(self.as_expression(), select, true)
+    }
+
+    /// `self IN table`
+    fn in_table<N: IntoName>(self, table: N) -> Result<Expression> {
+        table.with_name(|table| in_table(self.as_expression(), table, false))
+    }
+
+    /// `self NOT IN table`
+    fn not_in_table<N: IntoName>(self, table: N) -> Result<Expression> {
+        table.with_name(|table| in_table(self.as_expression(), table, true))
+    }
+
+    /// `self IN function(...)` for table-valued functions.
+    /// Use `Expression::argument` or `Expression::arguments` on the result to pass arguments to the function.
+    fn in_function<N: IntoName>(self, function: N) -> Result<Expression> {
+        function.with_name(|function| in_function(self.as_expression(), function, false))
+    }
+
+    /// `self NOT IN function(...)` for table-valued functions.
+    fn not_in_function<N: IntoName>(self, function: N) -> Result<Expression> {
+        function.with_name(|function| in_function(self.as_expression(), function, true))
+    }
+
+    /// `CAST(self AS column_type)`
+    fn cast(self, column_type: ColumnType) -> Expression {
+        let operand = self.as_expression();
+        let ptr = operand.with_raw(|o| unsafe { WCDBExpressionCast(o) });
+        let expression = Expression::from_raw(ptr);
+        expression.with_raw(|e| unsafe { WCDBExpressionAs(e, column_type.c()) });
+        expression
+    }
+
+    /// `self COLLATE collation`
+    fn collate<N: IntoName>(self, collation: N) -> Result<Expression> {
+        let operand = self.as_expression();
+        let ptr = collation.with_name(|collation| {
+            operand.with_raw(|o| unsafe { WCDBExpressionCollateOperate(o, collation.as_ptr()) })
+        })?;
+        Ok(Expression::from_raw(ptr))
+    }
+}
+
+impl ExpressionOperable for Expression {}
@@ -73,6 +73,18 @@ where
     }
 }
 
+impl<T, R> WithRawIdentifier<Vec<R>> for Vec<T>
+where
+    T: WithRawIdentifier<R>,
+{
+    fn with_raw<F, R1>(&self, f: F) -> R1
+    where
+        F: FnOnce(Vec<R>) -> R1,
+    {
+        f(self.iter().map(|t| t.with_raw(|r| r)).collect())
+    }
+}
+
 impl<T> Drop for Identifier<T>
 where
     T: Into<*mut CPPObject> + Clone + Copy,
@@ -1,8 +1,11 @@
-use std::ffi::CStr;
-
 use libwcdb_sys::CPPBindParameter;
 
-use super::identifier;
+use crate::{
+    error::Result,
+    winq::convert::{AsExpression, IntoName, NonTextExpression},
+};
+
+use super::{expression::Expression, identifier};
 
 identifier!(BindParameter<CPPBindParameter>);
 
@@ -13,10 +16,10 @@ macro_rules! f {
             BindParameter(ptr.into())
         }
     };
-    (pub fn $name:ident($p:ident : &CStr) = $f:ident) => {
-        pub fn $name($p: &CStr) -> BindParameter {
-            let ptr = unsafe { libwcdb_sys::$f($p.as_ptr()) };
-            BindParameter(ptr.into())
+    (pub fn $name:ident($p:ident : IntoName) = $f:ident) => {
+        pub fn $name<N: IntoName>($p: N) -> Result<BindParameter> {
+            let ptr = $p.with_name(|$p| unsafe { libwcdb_sys::$f($p.as_ptr()) })?;
+            Ok(BindParameter(ptr.into()))
         }
     };
 
@@ -29,10 +32,10 @@ macro_rules! f {
 
 impl BindParameter {
     f!(pub fn new(num: i32) = WCDBBindparameterCreateQuestionSignType);
-    f!(pub fn named(name: &CStr) = WCDBBindparameterCreateColonSignType);
-    f!(pub fn at(name: &CStr) = WCDBBindparameterCreateAtSignType);
-    f!(pub fn colon(name: &CStr) = WCDBBindparameterCreateColonSignType);
-    f!(pub fn dollar(name: &CStr) = WCDBBindparameterCreateDollarSignType);
+    f!(pub fn named(name: IntoName) = WCDBBindparameterCreateColonSignType);
+    f!(pub fn at(name: IntoName) = WCDBBindparameterCreateAtSignType);
+    f!(pub fn colon(name: IntoName) = WCDBBindparameterCreateColonSignType);
+    f!(pub fn dollar(name: IntoName) = WCDBBindparameterCreateDollarSignType);
 
     f!(def = 0);
     f!(_1 = 1);
@@ -59,18 +62,27 @@ impl BindParameter {
     }
 }
 
+impl AsExpression for BindParameter {
+    fn as_expression(self) -> Expression {
+        Expression::bind_parameter(self)
+    }
+}
+
+impl NonTextExpression for BindParameter {}
+
 #[cfg(test)]
 mod tests {
     use super::super::eq_sql;
     use super::*;
+    use crate::error::Result;
 
     #[test]
-    fn test_bind_parameter() {
+    fn test_bind_parameter() -> Result<()> {
         eq_sql!(BindParameter::new(1), "?1");
-        eq_sql!(BindParameter::named(c"name"), ":name");
-        eq_sql!(BindParameter::at(c"name"), "@name");
-        eq_sql!(BindParameter::colon(c"name"), ":name");
-        eq_sql!(BindParameter::dollar(c"name"), "$name");
+        eq_sql!(BindParameter::named(c"name")?, ":name");
+        eq_sql!(BindParameter::at(c"name")?, "@name");
+        eq_sql!(BindParameter::colon(c"name")?, ":name");
+        eq_sql!(BindParameter::dollar(c"name")?, "$name");
 
         eq_sql!(BindParameter::def(), "?");
         eq_sql!(BindParameter::_1(), "?1");
@@ -94,5 +106,6 @@ mod tests {
         assert_eq!(params[0].description(), "?1");
         assert_eq!(params[1].description(), "?2");
         assert_eq!(params[2].description(), "?3");
+        Ok(())
     }
 }
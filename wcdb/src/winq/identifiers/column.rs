@@ -2,24 +2,41 @@ use std::{ffi::CStr, os::raw::c_void, ptr};
 
 use libwcdb_sys::CPPColumn;
 
-use super::{identifier, result_column::ResultColumn, schema::Schema};
-use crate::winq::{
-    convert::{AsColumn, AsSchema},
-    identifier::WithRawIdentifier,
+use super::{expression::Expression, identifier, result_column::ResultColumn, schema::Schema};
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsColumn, AsExpression, AsSchema, IntoName, NonTextExpression},
+        expression_operable::ExpressionOperable,
+        identifier::WithRawIdentifier,
+    },
 };
 
 identifier!(Column<CPPColumn>);
 
 impl Column {
-    pub fn new(name: &CStr) -> Column {
+    pub fn new<N: IntoName>(name: N) -> Result<Column> {
         Column::new_with_table_binding(name, ptr::null())
     }
 
-    pub fn new_with_table_binding(name: &CStr, table_binding: *const c_void) -> Column {
+    pub fn new_with_table_binding<N: IntoName>(
+        name: N,
+        table_binding: *const c_void,
+    ) -> Result<Column> {
+        name.with_name(|name| Column::new_inner(name, table_binding))
+    }
+
+    fn new_inner(name: &CStr, table_binding: *const c_void) -> Column {
         let ptr = unsafe { libwcdb_sys::WCDBColumnCreateWithName(name.as_ptr(), table_binding) };
         Column(ptr.into())
     }
 
+    /// A column named by a Rust string in an ordering term, result column or indexed column,
+    /// whose conversions can not fail. Panics on an interior NUL byte.
+    pub(crate) fn named(name: &str) -> Column {
+        Column::new(name).expect("a column name can not hold an interior NUL byte")
+    }
+
     pub fn all() -> Column {
         let ptr = unsafe { libwcdb_sys::WCDBColumnCreateAll() };
         Column(ptr.into())
@@ -30,19 +47,22 @@ impl Column {
         Column(ptr.into())
     }
 
-    pub fn r#in(self, name: &CStr) -> Column {
-        self.with_raw(|t| unsafe { libwcdb_sys::WCDBColumnInTable(t, name.as_ptr()) });
-        self
+    pub fn r#in<N: IntoName>(self, name: N) -> Result<Column> {
+        name.with_name(|name| {
+            self.with_raw(|t| unsafe { libwcdb_sys::WCDBColumnInTable(t, name.as_ptr()) })
+        })?;
+        Ok(self)
     }
 
-    pub fn r#as(&self, alias: &CStr) -> ResultColumn {
-        let ptr =
-            self.with_raw(|r| unsafe { libwcdb_sys::WCDBColumnConfigAlias(r, alias.as_ptr()) });
-        ResultColumn::from_raw(ptr)
+    pub fn r#as<N: IntoName>(&self, alias: N) -> Result<ResultColumn> {
+        let ptr = alias.with_name(|alias| {
+            self.with_raw(|r| unsafe { libwcdb_sys::WCDBColumnConfigAlias(r, alias.as_ptr()) })
+        })?;
+        Ok(ResultColumn::from_raw(ptr))
     }
 
-    pub fn of<T: AsSchema>(self, schema: T) -> Column {
-        self.of_inner(schema.as_schema())
+    pub fn of<T: AsSchema>(self, schema: T) -> Result<Column> {
+        Ok(self.of_inner(schema.as_schema()?))
     }
 
     fn of_inner(self, schema: Schema) -> Column {
@@ -56,31 +76,59 @@ impl AsColumn for Column {
         self
     }
 
-    fn r#in(self, name: &CStr) -> Column {
+    fn r#in<N: IntoName>(self, name: N) -> Result<Column> {
         self.r#in(name)
     }
 
-    fn of<T: AsSchema>(self, schema: T) -> Column {
+    fn of<T: AsSchema>(self, schema: T) -> Result<Column> {
         self.of(schema)
     }
 }
 
+impl AsExpression for Column {
+    fn as_expression(self) -> Expression {
+        Expression::column(self)
+    }
+}
+
+impl NonTextExpression for Column {}
+
+impl ExpressionOperable for Column {}
+
 #[cfg(test)]
 mod tests {
     use super::super::eq_sql;
     use super::*;
+    use crate::error::Error;
 
     #[test]
-    fn test_column() {
-        eq_sql!(Column::new(c"name"), "name");
-        eq_sql!(Column::new_with_table_binding(c"name", ptr::null()), "name");
+    fn test_column() -> Result<()> {
+        eq_sql!(Column::new("name")?, "name");
+        eq_sql!(Column::new(c"name")?, "name");
+        eq_sql!(Column::new(String::from("name"))?, "name");
+        eq_sql!(Column::new_with_table_binding("name", ptr::null())?, "name");
         eq_sql!(Column::all(), "*");
         eq_sql!(Column::rowid(), "rowid");
-        eq_sql!(Column::new(c"name").r#in(c"table"), "table.name");
-        eq_sql!(Column::new(c"name").r#as(c"alias"), "name AS alias");
+        eq_sql!(Column::new("name")?.r#in("table")?, "table.name");
+        eq_sql!(Column::new("name")?.r#as("alias")?, "name AS alias");
         eq_sql!(
-            Column::new(c"name").r#in(c"t").r#as(c"alias"),
+            Column::new("name")?.r#in("t")?.r#as("alias")?,
             "t.name AS alias"
         );
+        Ok(())
+    }
+
+    #[test]
+    fn test_nul_name() -> Result<()> {
+        assert!(matches!(Column::new("na\0me"), Err(Error::NulError(_))));
+        assert!(matches!(
+            Column::new("name")?.r#in(String::from("t\0")),
+            Err(Error::NulError(_))
+        ));
+        assert!(matches!(
+            Column::new("name")?.of("s\0"),
+            Err(Error::NulError(_))
+        ));
+        Ok(())
     }
 }
@@ -1,11 +1,14 @@
-use std::{ffi::CStr, ptr};
+use std::ptr;
 
 use libwcdb_sys::CPPColumnConstraint;
 
-use crate::winq::{
-    convert::AsExpression,
-    identifier::WithRawIdentifier,
-    types::{Conflict, Order},
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsExpression, IntoName},
+        identifier::WithRawIdentifier,
+        types::{Conflict, Order},
+    },
 };
 
 use super::{expression::Expression, foreign_key::ForeignKey, identifier};
@@ -18,9 +21,10 @@ impl ColumnConstraint {
         ColumnConstraint(ptr.into())
     }
 
-    pub fn named(name: &CStr) -> ColumnConstraint {
-        let ptr = unsafe { libwcdb_sys::WCDBColumnConstraintCreate(name.as_ptr()) };
-        ColumnConstraint(ptr.into())
+    pub fn named<N: IntoName>(name: N) -> Result<ColumnConstraint> {
+        let ptr = name
+            .with_name(|name| unsafe { libwcdb_sys::WCDBColumnConstraintCreate(name.as_ptr()) })?;
+        Ok(ColumnConstraint(ptr.into()))
     }
 
     pub fn primary_key(self) -> ColumnConstraint {
@@ -72,11 +76,13 @@ impl ColumnConstraint {
         self
     }
 
-    pub fn collate(self, name: &CStr) -> ColumnConstraint {
-        self.with_raw(|t| unsafe {
-            libwcdb_sys::WCDBColumnConstraintConfigCollation(t, name.as_ptr())
-        });
-        self
+    pub fn collate<N: IntoName>(self, name: N) -> Result<ColumnConstraint> {
+        name.with_name(|name| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBColumnConstraintConfigCollation(t, name.as_ptr())
+            })
+        })?;
+        Ok(self)
     }
 
     pub fn foreign_key(self, key: ForeignKey) -> ColumnConstraint {
@@ -95,14 +101,15 @@ impl ColumnConstraint {
 mod tests {
     use super::super::eq_sql;
     use super::*;
+    use crate::error::Result;
 
     #[test]
-    fn test_column_constraint() {
+    fn test_column_constraint() -> Result<()> {
         eq_sql!(ColumnConstraint::new(), "");
 
         eq_sql!(ColumnConstraint::new().primary_key(), "PRIMARY KEY");
         eq_sql!(
-            ColumnConstraint::named(c"name").primary_key(),
+            ColumnConstraint::named(c"name")?.primary_key(),
             "CONSTRAINT name PRIMARY KEY"
         );
 
@@ -124,8 +131,9 @@ mod tests {
         eq_sql!(ColumnConstraint::new().not_null(), "NOT NULL");
         eq_sql!(ColumnConstraint::new().unique(), "UNIQUE");
         eq_sql!(
-            ColumnConstraint::named(c"name").collate(c"BINARY"),
+            ColumnConstraint::named(c"name")?.collate(c"BINARY")?,
             "CONSTRAINT name COLLATE BINARY"
         );
+        Ok(())
     }
 }
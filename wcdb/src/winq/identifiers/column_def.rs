@@ -1,11 +1,12 @@
-use std::ffi::CStr;
-
 use libwcdb_sys::CPPColumnDef;
 
-use crate::winq::{
-    convert::{AsColumn, AsExpression},
-    identifier::WithRawIdentifier,
-    types::{ColumnType, Conflict, Order},
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsColumn, AsExpression, IntoName},
+        identifier::WithRawIdentifier,
+        types::{ColumnType, Conflict, Order},
+    },
 };
 
 use super::{
@@ -28,14 +29,14 @@ impl ColumnDef {
         ColumnDef(ptr.into())
     }
 
-    pub fn named(name: &CStr) -> ColumnDef {
-        let column = Column::new(name);
-        ColumnDef::with(column)
+    pub fn named<N: IntoName>(name: N) -> Result<ColumnDef> {
+        let column = Column::new(name)?;
+        Ok(ColumnDef::with(column))
     }
 
-    pub fn named_with_type(name: &CStr, type_: ColumnType) -> ColumnDef {
-        let column = Column::new(name);
-        ColumnDef::with_type(column, type_)
+    pub fn named_with_type<N: IntoName>(name: N, type_: ColumnType) -> Result<ColumnDef> {
+        let column = Column::new(name)?;
+        Ok(ColumnDef::with_type(column, type_))
     }
 
     pub fn with_constraint(self, constraint: ColumnConstraint) -> ColumnDef {
@@ -128,27 +129,28 @@ impl ColumnDefPrimaryKey {
 mod tests {
     use super::super::eq_sql;
     use super::*;
+    use crate::error::Result;
 
     fn new_column_def() -> ColumnDef {
-        ColumnDef::named_with_type(c"name", ColumnType::Integer32)
+        ColumnDef::named_with_type(c"name", ColumnType::Integer32).unwrap()
     }
 
     #[test]
-    fn test_column_def() {
+    fn test_column_def() -> Result<()> {
         eq_sql!(
-            ColumnDef::named_with_type(c"name", ColumnType::Float),
+            ColumnDef::named_with_type(c"name", ColumnType::Float)?,
             "name REAL"
         );
         eq_sql!(
-            ColumnDef::named_with_type(c"name", ColumnType::Text),
+            ColumnDef::named_with_type(c"name", ColumnType::Text)?,
             "name TEXT"
         );
         eq_sql!(
-            ColumnDef::named_with_type(c"name", ColumnType::Blob),
+            ColumnDef::named_with_type(c"name", ColumnType::Blob)?,
             "name BLOB"
         );
         eq_sql!(
-            ColumnDef::named_with_type(c"name", ColumnType::Null),
+            ColumnDef::named_with_type(c"name", ColumnType::Null)?,
             "name NULL"
         );
 
@@ -168,5 +170,6 @@ mod tests {
             new_column_def().primary_key(ColumnDefPrimaryKey::new().on_conflict(Conflict::Abort)),
             "name INTEGER PRIMARY KEY ON CONFLICT ABORT"
         );
+        Ok(())
     }
 }
@@ -1,17 +1,23 @@
-use std::ffi::CStr;
-
 use libwcdb_sys::CPPCommonTableExpression;
 
-use crate::winq::{convert::AsColumn, identifier::WithRawIdentifier};
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsColumn, AsSelect, IntoName},
+        identifier::WithRawIdentifier,
+    },
+};
 
 use super::{column::Column, identifier};
 
 identifier!(CommonTableExpression<CPPCommonTableExpression>);
 
 impl CommonTableExpression {
-    pub fn new(table: &CStr) -> CommonTableExpression {
-        let ptr = unsafe { libwcdb_sys::WCDBCommonTableExpressionCreate(table.as_ptr()) };
-        CommonTableExpression(ptr.into())
+    pub fn new<N: IntoName>(table: N) -> Result<CommonTableExpression> {
+        let ptr = table.with_name(|table| unsafe {
+            libwcdb_sys::WCDBCommonTableExpressionCreate(table.as_ptr())
+        })?;
+        Ok(CommonTableExpression(ptr.into()))
     }
 
     pub fn column<T: AsColumn>(self, column: T) -> CommonTableExpression {
@@ -25,5 +31,36 @@ impl CommonTableExpression {
         self
     }
 
-    // fn as aslect
+    /// `AS (select)`
+    pub fn r#as<S: AsSelect>(self, select: S) -> CommonTableExpression {
+        (&self, &select)
+            .with_raw(|(t, s)| unsafe { libwcdb_sys::WCDBCommonTableExpressionAsSelection(t, s) });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{eq_sql, expression::Expression};
+    use super::*;
+    use crate::{error::Result, winq::statements::select::StatementSelect};
+
+    #[test]
+    fn test_common_table_expression() -> Result<()> {
+        eq_sql!(
+            CommonTableExpression::new(c"c")?
+                .column(Column::new(c"n")?)
+                .r#as(StatementSelect::new().select([Expression::integer(1)])),
+            "c(n) AS(SELECT 1)"
+        );
+        eq_sql!(
+            CommonTableExpression::new(c"c")?.r#as(
+                StatementSelect::new()
+                    .select([Column::new(c"a")?])
+                    .from(["t"])?
+            ),
+            "c AS(SELECT a FROM t)"
+        );
+        Ok(())
+    }
 }
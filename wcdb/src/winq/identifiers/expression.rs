@@ -1,5 +1,731 @@
+use std::ffi::CStr;
+
 use libwcdb_sys::CPPExpression;
 
-use super::identifier;
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsColumn, AsExpression, AsSelect, IntoName, NonTextExpression},
+        identifier::WithRawIdentifier,
+    },
+};
+
+use super::{bind::BindParameter, identifier, result_column::ResultColumn, window_def::WindowDef};
 
 identifier!(Expression<CPPExpression>);
+
+impl Expression {
+    pub fn integer(value: i64) -> Expression {
+        let ptr = unsafe { libwcdb_sys::WCDBExpressionCreateWithLong(value) };
+        Expression(ptr.into())
+    }
+
+    pub fn float(value: f64) -> Expression {
+        let ptr = unsafe { libwcdb_sys::WCDBExpressionCreateWithDouble(value) };
+        Expression(ptr.into())
+    }
+
+    pub fn bool(value: bool) -> Expression {
+        let ptr = unsafe { libwcdb_sys::WCDBExpressionCreateWithBool(value) };
+        Expression(ptr.into())
+    }
+
+    pub fn text<N: IntoName>(value: N) -> Result<Expression> {
+        let ptr = value.with_name(|value| unsafe {
+            libwcdb_sys::WCDBExpressionCreateWithString(value.as_ptr())
+        })?;
+        Ok(Expression(ptr.into()))
+    }
+
+    pub fn column<T: AsColumn>(column: T) -> Expression {
+        let column = column.as_column();
+        let ptr = column.with_raw(|c| unsafe { libwcdb_sys::WCDBExpressionCreateWithColumn(c) });
+        Expression(ptr.into())
+    }
+
+    pub fn bind_parameter(parameter: BindParameter) -> Expression {
+        let ptr = parameter
+            .with_raw(|p| unsafe { libwcdb_sys::WCDBExpressionCreateWithBindParameter(p) });
+        Expression(ptr.into())
+    }
+
+    /// A scalar subquery, e.g. `(SELECT max(a) FROM t)`.
+    pub fn subquery<S: AsSelect>(select: S) -> Expression {
+        let ptr = select.with_raw(|s| unsafe { libwcdb_sys::WCDBExpressionCreateWithSelection(s) });
+        Expression(ptr.into())
+    }
+
+    /// `EXISTS (select)`
+    pub fn exists<S: AsSelect>(select: S) -> Expression {
+        let ptr =
+            select.with_raw(|s| unsafe { libwcdb_sys::WCDBExpressionCreateWithExistStatement(s) });
+        Expression(ptr.into())
+    }
+
+    /// `NOT EXISTS (select)`
+    pub fn not_exists<S: AsSelect>(select: S) -> Expression {
+        let ptr = select
+            .with_raw(|s| unsafe { libwcdb_sys::WCDBExpressionCreateWithNotExistStatement(s) });
+        Expression(ptr.into())
+    }
+
+    pub fn r#as<N: IntoName>(&self, alias: N) -> Result<ResultColumn> {
+        let ptr = alias.with_name(|alias| {
+            self.with_raw(|e| unsafe { libwcdb_sys::WCDBExpressionConfigAlias(e, alias.as_ptr()) })
+        })?;
+        Ok(ResultColumn::from_raw(ptr))
+    }
+}
+
+// Function
+
+impl Expression {
+    /// Create a call of function `name`.
+    /// Call `invoke` or `invoke_all` before adding arguments to it.
+    pub fn function<N: IntoName>(name: N) -> Result<Expression> {
+        name.with_name(Expression::function_inner)
+    }
+
+    pub(super) fn function_inner(name: &CStr) -> Expression {
+        let ptr = unsafe { libwcdb_sys::WCDBExpressionCreateWithFunction(name.as_ptr()) };
+        Expression(ptr.into())
+    }
+
+    /// Invoke the function with arguments, e.g. `count(a)`.
+    pub fn invoke(self) -> Expression {
+        self.with_raw(|e| unsafe { libwcdb_sys::WCDBExpressionInvoke(e) });
+        self
+    }
+
+    /// Invoke the function with wildcard, e.g. `count(*)`.
+    pub fn invoke_all(self) -> Expression {
+        self.with_raw(|e| unsafe { libwcdb_sys::WCDBExpressionInvokeAll(e) });
+        self
+    }
+
+    /// Append an argument to the function.
+    pub fn argument<T: AsExpression>(self, argument: T) -> Expression {
+        self.argument_inner(argument.as_expression())
+    }
+
+    fn argument_inner(self, argument: Expression) -> Expression {
+        (&self, &argument).with_raw(|(e, a)| unsafe {
+            let value = libwcdb_sys::CPPCommonValue {
+                type_: libwcdb_sys::WCDBBridgedType_WCDBBridgedType_Expression,
+                __bindgen_anon_1: libwcdb_sys::CPPCommonValue__bindgen_ty_1 {
+                    intValue: a.innerValue as _,
+                },
+            };
+            libwcdb_sys::WCDBExpressionSetArgument(e, value)
+        });
+        self
+    }
+
+    /// Replace all arguments of the function.
+    pub fn arguments<T, I>(self, arguments: I) -> Expression
+    where
+        T: AsExpression,
+        I: IntoIterator<Item = T>,
+    {
+        let arguments: Vec<Expression> = arguments.into_iter().map(|a| a.as_expression()).collect();
+        self.arguments_inner(arguments)
+    }
+
+    fn arguments_inner(self, arguments: Vec<Expression>) -> Expression {
+        (&self, &arguments).with_raw(|(e, a)| unsafe {
+            libwcdb_sys::WCDBExpressionSetArguments(e, a.as_ptr(), a.len() as _)
+        });
+        self
+    }
+
+    /// Mark the arguments as `DISTINCT`, e.g. `count(DISTINCT a)`.
+    pub fn distinct(self) -> Expression {
+        self.with_raw(|e| unsafe { libwcdb_sys::WCDBExpressionDistinct(e) });
+        self
+    }
+
+    /// Add a `FILTER (WHERE condition)` clause to an aggregate function.
+    pub fn filter<T: AsExpression>(self, condition: T) -> Expression {
+        self.filter_inner(condition.as_expression())
+    }
+
+    fn filter_inner(self, condition: Expression) -> Expression {
+        (&self, &condition).with_raw(|(e, c)| unsafe { libwcdb_sys::WCDBExpressionFilter(e, c) });
+        self
+    }
+}
+
+// Window function
+
+impl Expression {
+    /// Create a call of window function `name`, which can be followed by an `OVER` clause.
+    /// Aggregate functions used as window functions, e.g. `sum(a) OVER (...)`, should be created by this method too.
+    pub fn window_function<N: IntoName>(name: N) -> Result<Expression> {
+        name.with_name(Expression::window_function_inner)
+    }
+
+    fn window_function_inner(name: &CStr) -> Expression {
+        let ptr = unsafe { libwcdb_sys::WCDBExpressionCreateWithWindowFunction(name.as_ptr()) };
+        Expression(ptr.into())
+    }
+
+    /// `OVER (window_def)`
+    pub fn over(self, window_def: WindowDef) -> Expression {
+        (&self, &window_def)
+            .with_raw(|(e, w)| unsafe { libwcdb_sys::WCDBExpressionOverWindowDef(e, w) });
+        self
+    }
+
+    /// `OVER window_name`, where the window is defined by `StatementSelect::window`.
+    pub fn over_window<N: IntoName>(self, window_name: N) -> Result<Expression> {
+        window_name.with_name(|window_name| {
+            self.with_raw(|e| unsafe {
+                libwcdb_sys::WCDBExpressionOverWindow(e, window_name.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+}
+
+// Case
+
+impl Expression {
+    /// `CASE WHEN ... THEN ... ELSE ... END`
+    pub fn case() -> CaseBuilder {
+        let ptr = unsafe { libwcdb_sys::WCDBExpressionCase() };
+        CaseBuilder(Expression(ptr.into()))
+    }
+
+    /// `CASE operand WHEN ... THEN ... ELSE ... END`
+    pub fn case_with<T: AsExpression>(operand: T) -> CaseBuilder {
+        let operand = operand.as_expression();
+        let ptr = operand.with_raw(|o| unsafe { libwcdb_sys::WCDBExpressionCaseWithExp(o) });
+        CaseBuilder(Expression(ptr.into()))
+    }
+
+    /// Add an `ESCAPE` clause to a `LIKE` expression, e.g. `a LIKE '%!%%' ESCAPE '!'`.
+    pub fn escape<T: AsExpression>(self, operand: T) -> Expression {
+        self.escape_inner(operand.as_expression())
+    }
+
+    fn escape_inner(self, operand: Expression) -> Expression {
+        (&self, &operand).with_raw(|(e, o)| unsafe { libwcdb_sys::WCDBExpressionEscapeWith(e, o) });
+        self
+    }
+}
+
+/// Builder of the `CASE` expression, which is created by `Expression::case` or `Expression::case_with`.
+pub struct CaseBuilder(Expression);
+
+impl CaseBuilder {
+    /// Add a `WHEN condition THEN value` branch.
+    pub fn when<T1: AsExpression, T2: AsExpression>(self, condition: T1, value: T2) -> CaseBuilder {
+        self.when_inner(condition.as_expression(), value.as_expression())
+    }
+
+    fn when_inner(self, condition: Expression, value: Expression) -> CaseBuilder {
+        (&self.0, (&condition, &value)).with_raw(|(e, (c, v))| unsafe {
+            libwcdb_sys::WCDBExpressionSetWithWhenExp(e, c);
+            libwcdb_sys::WCDBExpressionSetWithThenExp(e, v);
+        });
+        self
+    }
+
+    /// Add the `ELSE value` branch and finish the expression.
+    pub fn r#else<T: AsExpression>(self, value: T) -> Expression {
+        self.else_inner(value.as_expression())
+    }
+
+    fn else_inner(self, value: Expression) -> Expression {
+        (&self.0, &value)
+            .with_raw(|(e, v)| unsafe { libwcdb_sys::WCDBExpressionSetWithElseExp(e, v) });
+        self.0
+    }
+
+    /// Finish the expression without an `ELSE` branch.
+    pub fn end(self) -> Expression {
+        self.0
+    }
+}
+
+impl AsExpression for CaseBuilder {
+    fn as_expression(self) -> Expression {
+        self.end()
+    }
+}
+
+impl NonTextExpression for CaseBuilder {}
+
+impl AsExpression for Expression {
+    fn as_expression(self) -> Expression {
+        self
+    }
+}
+
+impl NonTextExpression for Expression {}
+
+macro_rules! f {
+    (@$ctor:ident $(#[$attr:meta])* pub fn $name:ident() = $f:literal) => {
+        $(#[$attr])*
+        pub fn $name() -> Expression {
+            Expression::$ctor($f).invoke()
+        }
+    };
+    (@$ctor:ident $(#[$attr:meta])* pub fn $name:ident(..) = $f:literal) => {
+        $(#[$attr])*
+        pub fn $name<T, I>(arguments: I) -> Expression
+        where
+            T: AsExpression,
+            I: IntoIterator<Item = T>,
+        {
+            Expression::$ctor($f).invoke().arguments(arguments)
+        }
+    };
+    (@$ctor:ident $(#[$attr:meta])* pub fn $name:ident($($p:ident),+) = $f:literal) => {
+        $(#[$attr])*
+        pub fn $name($($p: impl AsExpression),+) -> Expression {
+            Expression::$ctor($f)
+                .invoke()
+                .arguments([$($p.as_expression()),+])
+        }
+    };
+    ($(#[$attr:meta])* pub fn $name:ident $params:tt = window $f:literal) => {
+        f!(@window_function_inner $(#[$attr])* pub fn $name $params = $f);
+    };
+    ($(#[$attr:meta])* pub fn $name:ident $params:tt = $f:literal) => {
+        f!(@function_inner $(#[$attr])* pub fn $name $params = $f);
+    };
+}
+
+// Core functions
+
+impl Expression {
+    f!(pub fn abs(x) = c"abs");
+    f!(pub fn changes() = c"changes");
+    f!(pub fn char(..) = c"char");
+    f!(pub fn coalesce(..) = c"coalesce");
+    f!(pub fn format(..) = c"format");
+    f!(pub fn hex(x) = c"hex");
+    f!(pub fn ifnull(x, y) = c"ifnull");
+    f!(pub fn iif(x, y, z) = c"iif");
+    f!(pub fn instr(x, y) = c"instr");
+    f!(pub fn last_insert_rowid() = c"last_insert_rowid");
+    f!(pub fn length(x) = c"length");
+    f!(pub fn likelihood(x, y) = c"likelihood");
+    f!(pub fn likely(x) = c"likely");
+    f!(pub fn lower(x) = c"lower");
+    f!(pub fn ltrim(x) = c"ltrim");
+    f!(
+        /// The multi-argument `max(x, y, ...)`, which returns the maximum of its arguments.
+        pub fn max_of(..) = c"max"
+    );
+    f!(
+        /// The multi-argument `min(x, y, ...)`, which returns the minimum of its arguments.
+        pub fn min_of(..) = c"min"
+    );
+    f!(pub fn nullif(x, y) = c"nullif");
+    f!(pub fn printf(..) = c"printf");
+    f!(pub fn quote(x) = c"quote");
+    f!(pub fn random() = c"random");
+    f!(pub fn randomblob(n) = c"randomblob");
+    f!(pub fn replace(x, y, z) = c"replace");
+    f!(pub fn round(x) = c"round");
+    f!(pub fn round_to(x, digits) = c"round");
+    f!(pub fn rtrim(x) = c"rtrim");
+    f!(pub fn sign(x) = c"sign");
+    f!(pub fn sqlite_version() = c"sqlite_version");
+    f!(pub fn substr(x, start, length) = c"substr");
+    f!(pub fn total_changes() = c"total_changes");
+    f!(pub fn trim(x) = c"trim");
+    f!(pub fn type_of(x) = c"typeof");
+    f!(pub fn unicode(x) = c"unicode");
+    f!(pub fn unlikely(x) = c"unlikely");
+    f!(pub fn upper(x) = c"upper");
+    f!(pub fn zeroblob(n) = c"zeroblob");
+}
+
+// Date and time functions
+
+impl Expression {
+    f!(pub fn date(..) = c"date");
+    f!(pub fn time(..) = c"time");
+    f!(pub fn datetime(..) = c"datetime");
+    f!(pub fn julianday(..) = c"julianday");
+    f!(pub fn unixepoch(..) = c"unixepoch");
+    f!(pub fn timediff(x, y) = c"timediff");
+
+    /// `strftime(format, time_value, modifier, ...)`
+    pub fn strftime<F, T, I>(format: F, arguments: I) -> Expression
+    where
+        F: AsExpression,
+        T: AsExpression,
+        I: IntoIterator<Item = T>,
+    {
+        let arguments = std::iter::once(format.as_expression())
+            .chain(arguments.into_iter().map(|a| a.as_expression()));
+        Expression::function_inner(c"strftime")
+            .invoke()
+            .arguments(arguments)
+    }
+}
+
+// Math functions
+
+impl Expression {
+    f!(pub fn acos(x) = c"acos");
+    f!(pub fn acosh(x) = c"acosh");
+    f!(pub fn asin(x) = c"asin");
+    f!(pub fn asinh(x) = c"asinh");
+    f!(pub fn atan(x) = c"atan");
+    f!(pub fn atan2(y, x) = c"atan2");
+    f!(pub fn atanh(x) = c"atanh");
+    f!(pub fn ceil(x) = c"ceil");
+    f!(pub fn cos(x) = c"cos");
+    f!(pub fn cosh(x) = c"cosh");
+    f!(pub fn degrees(x) = c"degrees");
+    f!(pub fn exp(x) = c"exp");
+    f!(pub fn floor(x) = c"floor");
+    f!(pub fn ln(x) = c"ln");
+    f!(pub fn log(x) = c"log");
+    f!(pub fn log_base(base, x) = c"log");
+    f!(pub fn log2(x) = c"log2");
+    f!(pub fn log10(x) = c"log10");
+    f!(pub fn r#mod(x, y) = c"mod");
+    f!(pub fn pi() = c"pi");
+    f!(pub fn pow(x, y) = c"pow");
+    f!(pub fn radians(x) = c"radians");
+    f!(pub fn sin(x) = c"sin");
+    f!(pub fn sinh(x) = c"sinh");
+    f!(pub fn sqrt(x) = c"sqrt");
+    f!(pub fn tan(x) = c"tan");
+    f!(pub fn tanh(x) = c"tanh");
+    f!(pub fn trunc(x) = c"trunc");
+}
+
+// Aggregate functions
+
+impl Expression {
+    f!(pub fn avg(x) = c"avg");
+    f!(pub fn count(x) = c"count");
+    f!(pub fn group_concat(x) = c"group_concat");
+    f!(pub fn group_concat_with(x, separator) = c"group_concat");
+    f!(pub fn max(x) = c"max");
+    f!(pub fn min(x) = c"min");
+    f!(pub fn sum(x) = c"sum");
+    f!(pub fn total(x) = c"total");
+
+    /// `count(*)`
+    pub fn count_all() -> Expression {
+        Expression::function_inner(c"count").invoke_all()
+    }
+}
+
+// Window functions
+
+impl Expression {
+    f!(pub fn row_number() = window c"row_number");
+    f!(pub fn rank() = window c"rank");
+    f!(pub fn dense_rank() = window c"dense_rank");
+    f!(pub fn percent_rank() = window c"percent_rank");
+    f!(pub fn cume_dist() = window c"cume_dist");
+    f!(pub fn ntile(n) = window c"ntile");
+    f!(pub fn lag(x) = window c"lag");
+    f!(pub fn lag_with(x, offset, default) = window c"lag");
+    f!(pub fn lead(x) = window c"lead");
+    f!(pub fn lead_with(x, offset, default) = window c"lead");
+    f!(pub fn first_value(x) = window c"first_value");
+    f!(pub fn last_value(x) = window c"last_value");
+    f!(pub fn nth_value(x, n) = window c"nth_value");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{column::Column, eq_sql, frame_spec::FrameSpec};
+    use super::*;
+    use crate::error::Result;
+    use crate::winq::{
+        expression_operable::ExpressionOperable, statements::select::StatementSelect,
+        types::ColumnType,
+    };
+
+    fn column() -> Column {
+        Column::new(c"a").unwrap()
+    }
+
+    #[test]
+    fn test_expression() -> Result<()> {
+        eq_sql!(Expression::integer(1), "1");
+        eq_sql!(Expression::float(1.5), "1.5");
+        eq_sql!(Expression::bool(true), "TRUE");
+        eq_sql!(Expression::text(c"abc")?, "'abc'");
+        eq_sql!(Expression::column(column()), "a");
+        eq_sql!(Expression::bind_parameter(BindParameter::_1()), "?1");
+        eq_sql!(Expression::column(column()).r#as(c"b")?, "a AS b");
+        Ok(())
+    }
+
+    #[test]
+    fn test_operator() {
+        let one = || Expression::integer(1);
+
+        eq_sql!(column().negative(), "-a");
+        eq_sql!(column().positive(), "+a");
+        eq_sql!(column().tilde(), "~a");
+        eq_sql!(column().not(), "NOT a");
+        eq_sql!(column().is_null(), "a ISNULL");
+        eq_sql!(column().not_null(), "a NOTNULL");
+
+        eq_sql!(column().concat(one()), "a || 1");
+        eq_sql!(column().mul(one()), "a * 1");
+        eq_sql!(column().div(one()), "a / 1");
+        eq_sql!(column().rem(one()), "a % 1");
+        eq_sql!(column().add(one()), "a + 1");
+        eq_sql!(column().sub(one()), "a - 1");
+        eq_sql!(column().left_shift(one()), "a << 1");
+        eq_sql!(column().right_shift(one()), "a >> 1");
+        eq_sql!(column().bit_and(one()), "a & 1");
+        eq_sql!(column().bit_or(one()), "a | 1");
+        eq_sql!(column().lt(one()), "a < 1");
+        eq_sql!(column().le(one()), "a <= 1");
+        eq_sql!(column().gt(one()), "a > 1");
+        eq_sql!(column().ge(one()), "a >= 1");
+        eq_sql!(column().eq(one()), "a == 1");
+        eq_sql!(column().ne(one()), "a != 1");
+        eq_sql!(column().is(one()), "a IS 1");
+        eq_sql!(column().is_not(one()), "a IS NOT 1");
+        eq_sql!(column().and(one()), "a AND 1");
+        eq_sql!(column().or(one()), "a OR 1");
+        eq_sql!(column().like(one()), "a LIKE 1");
+        eq_sql!(column().not_like(one()), "a NOT LIKE 1");
+        eq_sql!(column().glob(one()), "a GLOB 1");
+        eq_sql!(column().not_glob(one()), "a NOT GLOB 1");
+        eq_sql!(column().regexp(one()), "a REGEXP 1");
+        eq_sql!(column().not_regexp(one()), "a NOT REGEXP 1");
+        eq_sql!(column().r#match(one()), "a MATCH 1");
+        eq_sql!(column().not_match(one()), "a NOT MATCH 1");
+        eq_sql!(
+            column().between(one(), Expression::integer(2)),
+            "a BETWEEN 1 AND 2"
+        );
+        eq_sql!(
+            column().not_between(one(), Expression::integer(2)),
+            "a NOT BETWEEN 1 AND 2"
+        );
+    }
+
+    #[test]
+    fn test_subquery() -> Result<()> {
+        let select = || {
+            StatementSelect::new()
+                .select([Column::new(c"b").unwrap()])
+                .from(["t"])
+                .unwrap()
+                .r#where(Column::new(c"c").unwrap().eq(column()))
+        };
+        eq_sql!(
+            Expression::exists(select()),
+            "EXISTS(SELECT b FROM t WHERE c == a)"
+        );
+        eq_sql!(
+            Expression::not_exists(select()),
+            "NOT EXISTS(SELECT b FROM t WHERE c == a)"
+        );
+        eq_sql!(
+            Expression::subquery(select()),
+            "(SELECT b FROM t WHERE c == a)"
+        );
+        eq_sql!(column().eq(select()), "a == (SELECT b FROM t WHERE c == a)");
+        eq_sql!(
+            column().in_select(select()),
+            "a IN(SELECT b FROM t WHERE c == a)"
+        );
+        eq_sql!(
+            column().not_in_select(select()),
+            "a NOT IN(SELECT b FROM t WHERE c == a)"
+        );
+        eq_sql!(column().in_table(c"t")?, "a IN t");
+        eq_sql!(column().not_in_table(c"t")?, "a NOT IN t");
+        eq_sql!(
+            column()
+                .in_function(c"json_each")?
+                .argument(Column::new(c"b")?),
+            "a IN json_each(b)"
+        );
+        eq_sql!(
+            column()
+                .not_in_function(c"json_each")?
+                .argument(Column::new(c"b")?),
+            "a NOT IN json_each(b)"
+        );
+        eq_sql!(
+            column().in_values([Expression::integer(1), Expression::integer(2)]),
+            "a IN(1, 2)"
+        );
+        eq_sql!(
+            column().not_in_values([Expression::integer(1), Expression::integer(2)]),
+            "a NOT IN(1, 2)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_case() -> Result<()> {
+        eq_sql!(
+            Expression::case()
+                .when(column().gt(Expression::integer(1)), Expression::text(c"x")?)
+                .end(),
+            "CASE WHEN a > 1 THEN 'x' END"
+        );
+        eq_sql!(
+            Expression::case_with(column())
+                .when(Expression::integer(1), Expression::integer(2))
+                .when(Expression::integer(3), Expression::integer(4))
+                .r#else(Expression::integer(5)),
+            "CASE a WHEN 1 THEN 2 WHEN 3 THEN 4 ELSE 5 END"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_collate_escape() -> Result<()> {
+        eq_sql!(column().cast(ColumnType::Integer64), "CAST(a AS INTEGER)");
+        eq_sql!(column().cast(ColumnType::Text), "CAST(a AS TEXT)");
+        eq_sql!(column().collate(c"NOCASE")?, "a COLLATE NOCASE");
+        eq_sql!(
+            column()
+                .like(Expression::text(c"%!%%")?)
+                .escape(Expression::text(c"!")?),
+            "a LIKE '%!%%' ESCAPE '!'"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_function() -> Result<()> {
+        eq_sql!(Expression::function(c"f")?.invoke(), "f()");
+        eq_sql!(Expression::function(c"f")?.invoke_all(), "f(*)");
+        eq_sql!(
+            Expression::function(c"f")?
+                .invoke()
+                .argument(column())
+                .argument(Expression::integer(1)),
+            "f(a, 1)"
+        );
+        eq_sql!(
+            Expression::function(c"f")?
+                .invoke()
+                .arguments([column(), Column::new(c"b")?]),
+            "f(a, b)"
+        );
+        eq_sql!(Expression::count_all(), "count(*)");
+        eq_sql!(Expression::count(column()).distinct(), "count(DISTINCT a)");
+        eq_sql!(
+            Expression::count_all().filter(column().gt(Expression::integer(1))),
+            "count(*) FILTER(WHERE a > 1)"
+        );
+        eq_sql!(
+            Expression::sum(column()).filter(column().not_null()),
+            "sum(a) FILTER(WHERE a NOTNULL)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_function() -> Result<()> {
+        let window = || {
+            WindowDef::new()
+                .partition([Column::new(c"b").unwrap()])
+                .order([Column::new(c"c").unwrap()])
+        };
+        eq_sql!(
+            Expression::row_number().over(window()),
+            "row_number() OVER(PARTITION BY b ORDER BY c)"
+        );
+        eq_sql!(Expression::rank().over_window(c"w")?, "rank() OVER w");
+        eq_sql!(
+            Expression::window_function(c"sum")?
+                .invoke()
+                .argument(column())
+                .over(window().frame_spec(
+                    FrameSpec::rows()
+                        .between_unbounded_preceding()
+                        .and_current_row()
+                )),
+            "sum(a) OVER(PARTITION BY b ORDER BY c ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)"
+        );
+        eq_sql!(
+            Expression::window_function(c"count")?
+                .invoke_all()
+                .filter(column().not_null())
+                .over_window(c"w")?,
+            "count(*) FILTER(WHERE a NOTNULL) OVER w"
+        );
+        eq_sql!(
+            Expression::lag_with(column(), Expression::integer(1), Expression::integer(0))
+                .over(window()),
+            "lag(a, 1, 0) OVER(PARTITION BY b ORDER BY c)"
+        );
+        eq_sql!(
+            Expression::ntile(Expression::integer(4)).over(window()),
+            "ntile(4) OVER(PARTITION BY b ORDER BY c)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_function_catalog() -> Result<()> {
+        eq_sql!(Expression::abs(column()), "abs(a)");
+        eq_sql!(Expression::changes(), "changes()");
+        eq_sql!(
+            Expression::coalesce([column(), Column::new(c"b")?]),
+            "coalesce(a, b)"
+        );
+        eq_sql!(
+            Expression::ifnull(column(), Expression::integer(0)),
+            "ifnull(a, 0)"
+        );
+        eq_sql!(
+            Expression::substr(column(), Expression::integer(1), Expression::integer(2)),
+            "substr(a, 1, 2)"
+        );
+        eq_sql!(Expression::type_of(column()), "typeof(a)");
+        eq_sql!(
+            Expression::max_of([column(), Column::new(c"b")?]),
+            "max(a, b)"
+        );
+
+        eq_sql!(
+            Expression::strftime(Expression::text(c"%Y")?, [column()]),
+            "strftime('%Y', a)"
+        );
+        eq_sql!(
+            Expression::datetime([Expression::text(c"now")?]),
+            "datetime('now')"
+        );
+        eq_sql!(
+            Expression::julianday(Vec::<Expression>::new()),
+            "julianday()"
+        );
+
+        eq_sql!(Expression::pi(), "pi()");
+        eq_sql!(
+            Expression::pow(column(), Expression::integer(2)),
+            "pow(a, 2)"
+        );
+        eq_sql!(
+            Expression::r#mod(column(), Expression::integer(2)),
+            "mod(a, 2)"
+        );
+
+        eq_sql!(Expression::avg(column()), "avg(a)");
+        eq_sql!(Expression::max(column()), "max(a)");
+        eq_sql!(Expression::min(column()), "min(a)");
+        eq_sql!(Expression::sum(column()), "sum(a)");
+        eq_sql!(Expression::total(column()), "total(a)");
+        eq_sql!(
+            Expression::group_concat_with(column(), Expression::text(c";")?),
+            "group_concat(a, ';')"
+        );
+        Ok(())
+    }
+}
@@ -1,5 +1,258 @@
 use libwcdb_sys::CPPForeignKey;
 
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsColumn, IntoName},
+        identifier::WithRawIdentifier,
+        types::MatchType,
+    },
+};
+
 use super::identifier;
 
 identifier!(ForeignKey<CPPForeignKey>);
+
+/// The action taken by `ON DELETE` and `ON UPDATE`.
+#[derive(Debug, Clone, Copy)]
+pub enum ForeignKeyAction {
+    SetNull,
+    SetDefault,
+    Cascade,
+    Restrict,
+    NoAction,
+}
+
+macro_rules! deferrable {
+    ($(#[$meta:meta])* $name:ident = $ffi:ident) => {
+        $(#[$meta])*
+        pub fn $name(self) -> ForeignKey {
+            self.with_raw(|f| unsafe { libwcdb_sys::$ffi(f) });
+            self
+        }
+    };
+}
+
+impl ForeignKey {
+    pub fn new() -> ForeignKey {
+        let ptr = unsafe { libwcdb_sys::WCDBForeignKeyCreate() };
+        ForeignKey(ptr.into())
+    }
+
+    /// `REFERENCES table`
+    pub fn references<N: IntoName>(self, table: N) -> Result<ForeignKey> {
+        table.with_name(|table| {
+            self.with_raw(|f| unsafe {
+                libwcdb_sys::WCDBForeignKeyConfigReferencesTable(f, table.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// `(column, ...)` of the referenced table.
+    pub fn columns<T, I>(self, columns: I) -> ForeignKey
+    where
+        T: AsColumn,
+        I: IntoIterator<Item = T>,
+    {
+        for column in columns {
+            let column = column.as_column();
+            (&self, &column)
+                .with_raw(|(f, c)| unsafe { libwcdb_sys::WCDBForeignKeyAddColumn(f, c) });
+        }
+        self
+    }
+
+    /// `ON DELETE action`
+    pub fn on_delete(self, action: ForeignKeyAction) -> ForeignKey {
+        self.with_raw(|f| unsafe {
+            match action {
+                ForeignKeyAction::SetNull => libwcdb_sys::WCDBForeignKeyConfigOnDeleteSetNull(f),
+                ForeignKeyAction::SetDefault => {
+                    libwcdb_sys::WCDBForeignKeyConfigOnDeleteSetDefault(f)
+                }
+                ForeignKeyAction::Cascade => libwcdb_sys::WCDBForeignKeyConfigOnDeleteCascade(f),
+                ForeignKeyAction::Restrict => libwcdb_sys::WCDBForeignKeyConfigOnDeleteRestrict(f),
+                ForeignKeyAction::NoAction => libwcdb_sys::WCDBForeignKeyConfigOnDeleteNoAction(f),
+            }
+        });
+        self
+    }
+
+    /// `ON UPDATE action`
+    pub fn on_update(self, action: ForeignKeyAction) -> ForeignKey {
+        self.with_raw(|f| unsafe {
+            match action {
+                ForeignKeyAction::SetNull => libwcdb_sys::WCDBForeignKeyConfigOnUpdateSetNull(f),
+                ForeignKeyAction::SetDefault => {
+                    libwcdb_sys::WCDBForeignKeyConfigOnUpdateSetDefault(f)
+                }
+                ForeignKeyAction::Cascade => libwcdb_sys::WCDBForeignKeyConfigOnUpdateCascade(f),
+                ForeignKeyAction::Restrict => libwcdb_sys::WCDBForeignKeyConfigOnUpdateRestrict(f),
+                ForeignKeyAction::NoAction => libwcdb_sys::WCDBForeignKeyConfigOnUpdateNoAction(f),
+            }
+        });
+        self
+    }
+
+    /// `MATCH match_type`
+    pub fn r#match(self, match_type: MatchType) -> ForeignKey {
+        self.with_raw(|f| unsafe { libwcdb_sys::WCDBForeignKeyConfigMatch(f, match_type.c()) });
+        self
+    }
+
+    deferrable!(
+        /// `DEFERRABLE`
+        deferrable = WCDBForeignKeyConfigDeferrable
+    );
+    deferrable!(
+        /// `DEFERRABLE INITIALLY DEFERRED`
+        deferrable_initially_deferred = WCDBForeignKeyConfigDeferrableInitiallyDeferred
+    );
+    deferrable!(
+        /// `DEFERRABLE INITIALLY IMMEDIATE`
+        deferrable_initially_immediate = WCDBForeignKeyConfigDeferrableInitiallyImmediate
+    );
+    deferrable!(
+        /// `NOT DEFERRABLE`
+        not_deferrable = WCDBForeignKeyConfigNotDeferrable
+    );
+    deferrable!(
+        /// `NOT DEFERRABLE INITIALLY DEFERRED`
+        not_deferrable_initially_deferred = WCDBForeignKeyConfigNotDeferrableInitiallyDeferred
+    );
+    deferrable!(
+        /// `NOT DEFERRABLE INITIALLY IMMEDIATE`
+        not_deferrable_initially_immediate = WCDBForeignKeyConfigNotDeferrableInitiallyImmediate
+    );
+}
+
+impl Default for ForeignKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{column::Column, column_def::ColumnDef, eq_sql};
+    use super::*;
+    use crate::error::Result;
+    use crate::winq::types::ColumnType;
+
+    fn new_foreign_key() -> ForeignKey {
+        ForeignKey::new()
+            .references(c"t")
+            .unwrap()
+            .columns([Column::new(c"a").unwrap()])
+    }
+
+    #[test]
+    fn test_foreign_key() -> Result<()> {
+        eq_sql!(ForeignKey::new().references(c"t")?, "REFERENCES t");
+        eq_sql!(new_foreign_key(), "REFERENCES t(a)");
+        eq_sql!(
+            ForeignKey::new()
+                .references(c"t")?
+                .columns([Column::new(c"a")?, Column::new(c"b")?]),
+            "REFERENCES t(a, b)"
+        );
+        eq_sql!(
+            ColumnDef::named_with_type(c"name", ColumnType::Integer32)?
+                .foreign_key(new_foreign_key()),
+            "name INTEGER REFERENCES t(a)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_foreign_key_action() {
+        eq_sql!(
+            new_foreign_key().on_delete(ForeignKeyAction::SetNull),
+            "REFERENCES t(a) ON DELETE SET NULL"
+        );
+        eq_sql!(
+            new_foreign_key().on_delete(ForeignKeyAction::SetDefault),
+            "REFERENCES t(a) ON DELETE SET DEFAULT"
+        );
+        eq_sql!(
+            new_foreign_key().on_delete(ForeignKeyAction::Cascade),
+            "REFERENCES t(a) ON DELETE CASCADE"
+        );
+        eq_sql!(
+            new_foreign_key().on_delete(ForeignKeyAction::Restrict),
+            "REFERENCES t(a) ON DELETE RESTRICT"
+        );
+        eq_sql!(
+            new_foreign_key().on_delete(ForeignKeyAction::NoAction),
+            "REFERENCES t(a) ON DELETE NO ACTION"
+        );
+        eq_sql!(
+            new_foreign_key().on_update(ForeignKeyAction::SetNull),
+            "REFERENCES t(a) ON UPDATE SET NULL"
+        );
+        eq_sql!(
+            new_foreign_key().on_update(ForeignKeyAction::SetDefault),
+            "REFERENCES t(a) ON UPDATE SET DEFAULT"
+        );
+        eq_sql!(
+            new_foreign_key().on_update(ForeignKeyAction::Cascade),
+            "REFERENCES t(a) ON UPDATE CASCADE"
+        );
+        eq_sql!(
+            new_foreign_key().on_update(ForeignKeyAction::Restrict),
+            "REFERENCES t(a) ON UPDATE RESTRICT"
+        );
+        eq_sql!(
+            new_foreign_key().on_update(ForeignKeyAction::NoAction),
+            "REFERENCES t(a) ON UPDATE NO ACTION"
+        );
+        eq_sql!(
+            new_foreign_key()
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::Restrict),
+            "REFERENCES t(a) ON DELETE CASCADE ON UPDATE RESTRICT"
+        );
+    }
+
+    #[test]
+    fn test_foreign_key_match() {
+        eq_sql!(
+            new_foreign_key().r#match(MatchType::Simple),
+            "REFERENCES t(a) MATCH SIMPLE"
+        );
+        eq_sql!(
+            new_foreign_key().r#match(MatchType::Full),
+            "REFERENCES t(a) MATCH FULL"
+        );
+        eq_sql!(
+            new_foreign_key().r#match(MatchType::Partial),
+            "REFERENCES t(a) MATCH PARTIAL"
+        );
+    }
+
+    #[test]
+    fn test_foreign_key_deferrable() {
+        eq_sql!(new_foreign_key().deferrable(), "REFERENCES t(a) DEFERRABLE");
+        eq_sql!(
+            new_foreign_key().deferrable_initially_deferred(),
+            "REFERENCES t(a) DEFERRABLE INITIALLY DEFERRED"
+        );
+        eq_sql!(
+            new_foreign_key().deferrable_initially_immediate(),
+            "REFERENCES t(a) DEFERRABLE INITIALLY IMMEDIATE"
+        );
+        eq_sql!(
+            new_foreign_key().not_deferrable(),
+            "REFERENCES t(a) NOT DEFERRABLE"
+        );
+        eq_sql!(
+            new_foreign_key().not_deferrable_initially_deferred(),
+            "REFERENCES t(a) NOT DEFERRABLE INITIALLY DEFERRED"
+        );
+        eq_sql!(
+            new_foreign_key().not_deferrable_initially_immediate(),
+            "REFERENCES t(a) NOT DEFERRABLE INITIALLY IMMEDIATE"
+        );
+    }
+}
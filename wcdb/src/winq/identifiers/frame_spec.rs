@@ -1,5 +1,119 @@
 use libwcdb_sys::CPPFrameSpec;
 
-use super::identifier;
+use crate::winq::{convert::AsExpression, identifier::WithRawIdentifier};
+
+use super::{expression::Expression, identifier};
 
 identifier!(FrameSpec<CPPFrameSpec>);
+
+macro_rules! f {
+    ($name:ident = $f:ident) => {
+        pub fn $name(self) -> FrameSpec {
+            self.with_raw(|t| unsafe { libwcdb_sys::$f(t) });
+            self
+        }
+    };
+    ($name:ident(expression) = $f:ident) => {
+        pub fn $name<T: AsExpression>(self, expression: T) -> FrameSpec {
+            let expression: Expression = expression.as_expression();
+            (&self, &expression).with_raw(|(t, e)| unsafe { libwcdb_sys::$f(t, e) });
+            self
+        }
+    };
+}
+
+impl FrameSpec {
+    pub fn new() -> FrameSpec {
+        let ptr = unsafe { libwcdb_sys::WCDBFrameSpecCreate() };
+        FrameSpec(ptr.into())
+    }
+
+    /// `RANGE ...`
+    pub fn range() -> FrameSpec {
+        let frame_spec = FrameSpec::new();
+        frame_spec.with_raw(|t| unsafe { libwcdb_sys::WCDBFrameSpecConfigRange(t) });
+        frame_spec
+    }
+
+    /// `ROWS ...`
+    pub fn rows() -> FrameSpec {
+        let frame_spec = FrameSpec::new();
+        frame_spec.with_raw(|t| unsafe { libwcdb_sys::WCDBFrameSpecConfigRows(t) });
+        frame_spec
+    }
+
+    f!(unbounded_preceding = WCDBFrameSpecConfigUnboundedPreceding);
+    f!(preceding(expression) = WCDBFrameSpecConfigPreceding);
+    f!(current_row = WCDBFrameSpecConfigCurrentRow);
+
+    f!(between_unbounded_preceding = WCDBFrameSpecConfigBetweenUnboundedPreceding);
+    f!(between_preceding(expression) = WCDBFrameSpecConfigBetweenPreceding);
+    f!(between_current_row = WCDBFrameSpecConfigBetweenCurrentRow);
+    f!(between_following(expression) = WCDBFrameSpecConfigBetweenFollowing);
+
+    f!(and_preceding(expression) = WCDBFrameSpecConfigAndPreceding);
+    f!(and_current_row = WCDBFrameSpecConfigAndCurrentRow);
+    f!(and_following(expression) = WCDBFrameSpecConfigAndFollowing);
+    f!(and_unbounded_following = WCDBFrameSpecConfigAndUnboundedFollowing);
+}
+
+impl Default for FrameSpec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::eq_sql;
+    use super::*;
+
+    fn one() -> Expression {
+        Expression::integer(1)
+    }
+
+    #[test]
+    fn test_frame_spec() {
+        eq_sql!(
+            FrameSpec::range().unbounded_preceding(),
+            "RANGE UNBOUNDED PRECEDING"
+        );
+        eq_sql!(
+            FrameSpec::rows().unbounded_preceding(),
+            "ROWS UNBOUNDED PRECEDING"
+        );
+        eq_sql!(FrameSpec::rows().preceding(one()), "ROWS 1 PRECEDING");
+        eq_sql!(FrameSpec::rows().current_row(), "ROWS CURRENT ROW");
+
+        eq_sql!(
+            FrameSpec::rows()
+                .between_unbounded_preceding()
+                .and_current_row(),
+            "ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW"
+        );
+        eq_sql!(
+            FrameSpec::rows()
+                .between_preceding(one())
+                .and_following(one()),
+            "ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING"
+        );
+        eq_sql!(
+            FrameSpec::range()
+                .between_current_row()
+                .and_unbounded_following(),
+            "RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING"
+        );
+        eq_sql!(
+            FrameSpec::range()
+                .between_following(one())
+                .and_following(Expression::integer(2)),
+            "RANGE BETWEEN 1 FOLLOWING AND 2 FOLLOWING"
+        );
+        eq_sql!(
+            FrameSpec::rows()
+                .between_unbounded_preceding()
+                .and_preceding(one()),
+            "ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING"
+        );
+    }
+}
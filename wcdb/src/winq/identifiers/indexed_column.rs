@@ -1,5 +1,99 @@
 use libwcdb_sys::CPPIndexedColumn;
 
-use super::identifier;
+use crate::{
+    error::Result,
+    winq::{
+        convert::{
+            AsExpression, AsIndexedColumn, AsOrderedIndexedColumn, IntoName, NonTextExpression,
+        },
+        identifier::WithRawIdentifier,
+        types::Order,
+    },
+};
+
+use super::{column::Column, expression::Expression, identifier};
 
 identifier!(IndexedColumn<CPPIndexedColumn>);
+
+impl IndexedColumn {
+    pub fn new<T: AsExpression>(expression: T) -> IndexedColumn {
+        IndexedColumn::new_inner(expression.as_expression())
+    }
+
+    fn new_inner(expression: Expression) -> IndexedColumn {
+        let ptr = expression
+            .with_raw(|e| unsafe { libwcdb_sys::WCDBIndexedColumnCreateWithExpression(e) });
+        IndexedColumn(ptr.into())
+    }
+
+    pub fn collate<N: IntoName>(self, collation_name: N) -> Result<IndexedColumn> {
+        collation_name.with_name(|name| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBIndexedColumnConfigCollation(t, name.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+
+    pub fn order(self, order: Order) -> IndexedColumn {
+        self.with_raw(|t| unsafe { libwcdb_sys::WCDBIndexedColumnConfigOrder(t, order.c()) });
+        self
+    }
+}
+
+impl AsIndexedColumn for IndexedColumn {
+    fn as_index(self) -> IndexedColumn {
+        self
+    }
+}
+
+impl<T: NonTextExpression> AsIndexedColumn for T {
+    fn as_index(self) -> IndexedColumn {
+        IndexedColumn::new(self)
+    }
+}
+
+macro_rules! column_name {
+    ($($ty:ty),*) => {
+        $(
+            impl AsIndexedColumn for $ty {
+                fn as_index(self) -> IndexedColumn {
+                    Column::named(self.as_ref()).as_index()
+                }
+            }
+        )*
+    };
+}
+
+column_name!(&str, String, &String);
+
+impl<T: AsIndexedColumn> AsOrderedIndexedColumn for T {
+    fn as_ordered_index(self, order: Order) -> IndexedColumn {
+        self.as_index().order(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::eq_sql;
+    use super::*;
+    use crate::error::Result;
+
+    #[test]
+    fn test_indexed_column() -> Result<()> {
+        eq_sql!(IndexedColumn::new(Column::new(c"a")?), "a");
+        eq_sql!(Column::new(c"a")?.as_index(), "a");
+        eq_sql!(Column::new(c"a")?.as_ordered_index(Order::Desc), "a DESC");
+        eq_sql!(
+            Column::new(c"a")?
+                .as_index()
+                .collate(c"NOCASE")?
+                .order(Order::Asc),
+            "a COLLATE NOCASE ASC"
+        );
+        eq_sql!(Expression::lower(Column::new(c"a")?).as_index(), "lower(a)");
+        eq_sql!("a".as_index(), "a");
+        eq_sql!(String::from("a").as_ordered_index(Order::Desc), "a DESC");
+        Ok(())
+    }
+}
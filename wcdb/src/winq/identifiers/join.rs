@@ -1,5 +1,178 @@
 use libwcdb_sys::CPPJoin;
 
-use super::identifier;
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsColumn, AsExpression, AsTableOrSubquery},
+        identifier::WithRawIdentifier,
+    },
+};
+
+use super::{
+    column::Column, expression::Expression, identifier, table_or_subquery::TableOrSubquery,
+};
 
 identifier!(Join<CPPJoin>);
+
+macro_rules! join {
+    ($(#[$meta:meta])* $name:ident = $ffi:ident) => {
+        $(#[$meta])*
+        pub fn $name<T: AsTableOrSubquery>(self, table: T) -> Result<Join> {
+            let table = table.as_table_or_subquery()?;
+            (&self, &table).with_raw(|(j, t)| unsafe { libwcdb_sys::$ffi(j, t) });
+            Ok(self)
+        }
+    };
+}
+
+impl Join {
+    pub fn new<T: AsTableOrSubquery>(table: T) -> Result<Join> {
+        Ok(Join::new_inner(table.as_table_or_subquery()?))
+    }
+
+    fn new_inner(table: TableOrSubquery) -> Join {
+        let ptr = table.with_raw(|t| unsafe { libwcdb_sys::WCDBJoinCreateWithTableOrSubquery(t) });
+        Join(ptr.into())
+    }
+
+    join!(
+        /// `self, table`
+        with = WCDBJoinWith
+    );
+    join!(
+        /// `self JOIN table`
+        join = WCDBJoinWithJoin
+    );
+    join!(
+        /// `self LEFT OUTER JOIN table`
+        left_outer_join = WCDBJoinWithLeftOuterJoin
+    );
+    join!(
+        /// `self LEFT JOIN table`
+        left_join = WCDBJoinWithLeftJoin
+    );
+    join!(
+        /// `self INNER JOIN table`
+        inner_join = WCDBJoinWithInnerJoin
+    );
+    join!(
+        /// `self CROSS JOIN table`
+        cross_join = WCDBJoinWithCrossJoin
+    );
+    join!(
+        /// `self NATURAL JOIN table`
+        natural_join = WCDBJoinWithNaturalJoin
+    );
+    join!(
+        /// `self NATURAL LEFT OUTER JOIN table`
+        natural_left_outer_join = WCDBJoinWithNaturalLeftOuterJoin
+    );
+    join!(
+        /// `self NATURAL LEFT JOIN table`
+        natural_left_join = WCDBJoinWithNaturalLeftJoin
+    );
+    join!(
+        /// `self NATURAL INNER JOIN table`
+        natural_inner_join = WCDBJoinWithNaturalInnerJoin
+    );
+    join!(
+        /// `self NATURAL CROSS JOIN table`
+        natural_cross_join = WCDBJoinWithNaturalCrossJoin
+    );
+
+    /// `ON expr`, constraining the last joined table.
+    pub fn on<T: AsExpression>(self, expr: T) -> Join {
+        self.on_inner(expr.as_expression())
+    }
+
+    fn on_inner(self, expr: Expression) -> Join {
+        (&self, &expr).with_raw(|(j, e)| unsafe { libwcdb_sys::WCDBJoinConfigOn(j, e) });
+        self
+    }
+
+    /// `USING (column, ...)`, constraining the last joined table.
+    pub fn using<T, I>(self, columns: I) -> Join
+    where
+        T: AsColumn,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.as_column()).collect();
+        self.using_inner(columns)
+    }
+
+    fn using_inner(self, columns: Vec<Column>) -> Join {
+        (&self, &columns).with_raw(|(j, c)| unsafe {
+            libwcdb_sys::WCDBJoinConfigUsingColumn(j, c.as_ptr(), c.len() as _)
+        });
+        self
+    }
+}
+
+impl AsTableOrSubquery for Join {
+    fn as_table_or_subquery(self) -> Result<TableOrSubquery> {
+        let ptr = self.with_raw(|j| unsafe { libwcdb_sys::WCDBTableOrSubqueryCreateWithJoin(j) });
+        Ok(TableOrSubquery::from_raw(ptr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::eq_sql;
+    use super::*;
+    use crate::winq::{
+        expression_operable::ExpressionOperable, statements::select::StatementSelect,
+    };
+
+    #[test]
+    fn test_join() -> Result<()> {
+        eq_sql!(Join::new("a")?.with("b")?, "a, b");
+        eq_sql!(Join::new("a")?.join("b")?, "a JOIN b");
+        eq_sql!(Join::new("a")?.left_outer_join("b")?, "a LEFT OUTER JOIN b");
+        eq_sql!(Join::new("a")?.left_join("b")?, "a LEFT JOIN b");
+        eq_sql!(Join::new("a")?.inner_join("b")?, "a INNER JOIN b");
+        eq_sql!(Join::new("a")?.cross_join("b")?, "a CROSS JOIN b");
+        eq_sql!(Join::new("a")?.natural_join("b")?, "a NATURAL JOIN b");
+        eq_sql!(
+            Join::new("a")?.natural_left_outer_join("b")?,
+            "a NATURAL LEFT OUTER JOIN b"
+        );
+        eq_sql!(
+            Join::new("a")?.natural_left_join("b")?,
+            "a NATURAL LEFT JOIN b"
+        );
+        eq_sql!(
+            Join::new("a")?.natural_inner_join("b")?,
+            "a NATURAL INNER JOIN b"
+        );
+        eq_sql!(
+            Join::new("a")?.natural_cross_join("b")?,
+            "a NATURAL CROSS JOIN b"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_constraint() -> Result<()> {
+        eq_sql!(
+            Join::new("a")?.left_join("b")?.on(Column::new(c"id")?
+                .r#in(c"a")?
+                .eq(Column::new(c"id")?.r#in(c"b")?)),
+            "a LEFT JOIN b ON a.id == b.id"
+        );
+        eq_sql!(
+            Join::new("a")?
+                .join("b")?
+                .using([Column::new(c"id")?, Column::new(c"name")?])
+                .join(String::from("c"))?
+                .using([Column::new(c"id")?]),
+            "a JOIN b USING(id, name) JOIN c USING(id)"
+        );
+        eq_sql!(
+            StatementSelect::new()
+                .select([Column::all()])
+                .from([Join::new("a")?.cross_join("b")?])?,
+            "SELECT * FROM a CROSS JOIN b"
+        );
+        Ok(())
+    }
+}
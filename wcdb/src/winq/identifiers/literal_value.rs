@@ -1,5 +1,258 @@
 use libwcdb_sys::CPPLiteralValue;
 
-use super::identifier;
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsExpression, AsLiteralValue, IntoName, NonTextExpression},
+        identifier::WithRawIdentifier,
+        value::Value,
+    },
+};
+
+use super::{column::Column, expression::Expression, identifier};
 
 identifier!(LiteralValue<CPPLiteralValue>);
+
+macro_rules! f {
+    ($name:ident = $f:ident) => {
+        pub fn $name() -> LiteralValue {
+            let ptr = unsafe { libwcdb_sys::$f() };
+            LiteralValue(ptr.into())
+        }
+    };
+}
+
+impl LiteralValue {
+    pub fn integer(value: i64) -> LiteralValue {
+        let ptr = unsafe { libwcdb_sys::WCDBLiteralValueCreateWithInt64(value) };
+        LiteralValue(ptr.into())
+    }
+
+    pub fn unsigned(value: u64) -> LiteralValue {
+        let ptr = unsafe { libwcdb_sys::WCDBLiteralValueCreateWithUInt64(value) };
+        LiteralValue(ptr.into())
+    }
+
+    pub fn float(value: f64) -> LiteralValue {
+        let ptr = unsafe { libwcdb_sys::WCDBLiteralValueCreateWithDouble(value) };
+        LiteralValue(ptr.into())
+    }
+
+    pub fn bool(value: bool) -> LiteralValue {
+        let ptr = unsafe { libwcdb_sys::WCDBLiteralValueCreateWithBool(value) };
+        LiteralValue(ptr.into())
+    }
+
+    /// Fails with `Error::NulError` if `value` contains an interior NUL byte,
+    /// which a text literal can not hold.
+    pub fn text<N: IntoName>(value: N) -> Result<LiteralValue> {
+        let ptr = value.with_name(|value| unsafe {
+            libwcdb_sys::WCDBLiteralValueCreateWithString(value.as_ptr())
+        })?;
+        Ok(LiteralValue(ptr.into()))
+    }
+
+    f!(null = WCDBLiteralValueCreateWithNull);
+    f!(current_time = WCDBLiteralValueCreateWithCurrentTime);
+    f!(current_date = WCDBLiteralValueCreateWithCurrentDate);
+    f!(current_timestamp = WCDBLiteralValueCreateWithCurrentTimestamp);
+}
+
+impl AsLiteralValue for LiteralValue {
+    fn as_literal_value(self) -> Result<LiteralValue> {
+        Ok(self)
+    }
+}
+
+impl AsExpression for LiteralValue {
+    fn as_expression(self) -> Expression {
+        let ptr =
+            self.with_raw(|l| unsafe { libwcdb_sys::WCDBExpressionCreateWithLiteralValue(l) });
+        Expression::from_raw(ptr)
+    }
+}
+
+impl NonTextExpression for LiteralValue {}
+
+macro_rules! literal {
+    ($($ty:ty => |$v:ident| $e:expr;)*) => {
+        $(
+            impl AsLiteralValue for $ty {
+                fn as_literal_value(self) -> Result<LiteralValue> {
+                    let $v = self;
+                    Ok($e)
+                }
+            }
+
+            impl AsExpression for $ty {
+                fn as_expression(self) -> Expression {
+                    let $v = self;
+                    $e.as_expression()
+                }
+            }
+
+            impl NonTextExpression for $ty {}
+        )*
+    };
+}
+
+literal! {
+    i32 => |v| LiteralValue::integer(v as _);
+    i64 => |v| LiteralValue::integer(v);
+    u64 => |v| LiteralValue::unsigned(v);
+    f64 => |v| LiteralValue::float(v);
+    bool => |v| LiteralValue::bool(v);
+    () => |_v| LiteralValue::null();
+}
+
+/// A text literal, or `char(code_point, ...)` for text with an interior NUL byte.
+fn text_expression(value: &str) -> Expression {
+    match LiteralValue::text(value) {
+        Ok(literal) => literal.as_expression(),
+        Err(_) => Expression::function_inner(c"char")
+            .invoke()
+            .arguments(value.chars().map(|c| c as i64)),
+    }
+}
+
+macro_rules! text {
+    ($($ty:ty),*) => {
+        $(
+            impl AsLiteralValue for $ty {
+                fn as_literal_value(self) -> Result<LiteralValue> {
+                    LiteralValue::text(self)
+                }
+            }
+
+            impl AsExpression for $ty {
+                fn as_expression(self) -> Expression {
+                    text_expression(self.as_ref())
+                }
+            }
+        )*
+    };
+}
+
+text!(&str, String, &String);
+
+/// A blob literal, `X'0A1B...'`. `LiteralValue` has no blob form in WINQ,
+/// so it is written as a bare column name, which WINQ renders verbatim.
+fn blob_expression(value: &[u8]) -> Expression {
+    let hex: String = value.iter().map(|b| format!("{:02X}", b)).collect();
+    let literal = format!("X'{}'", hex);
+    // Hex digits hold no NUL byte.
+    Column::new(literal.as_str()).unwrap().as_expression()
+}
+
+/// Text is written as `text_expression` does, and blobs as `X'...'` hex literals.
+/// `Value` is an `AsExpression` only, since `LiteralValue` can not hold a blob.
+impl AsExpression for Value {
+    fn as_expression(self) -> Expression {
+        match self {
+            Value::Null => LiteralValue::null().as_expression(),
+            Value::Integer32(v) => LiteralValue::integer(v as _).as_expression(),
+            Value::Integer64(v) => LiteralValue::integer(v).as_expression(),
+            Value::Float(v) => LiteralValue::float(v).as_expression(),
+            Value::Text(v) => text_expression(&v),
+            Value::Blob(v) => blob_expression(&v),
+        }
+    }
+}
+
+impl<T: AsLiteralValue> AsLiteralValue for Option<T> {
+    fn as_literal_value(self) -> Result<LiteralValue> {
+        match self {
+            Some(value) => value.as_literal_value(),
+            None => Ok(LiteralValue::null()),
+        }
+    }
+}
+
+impl<T: AsExpression> AsExpression for Option<T> {
+    fn as_expression(self) -> Expression {
+        match self {
+            Some(value) => value.as_expression(),
+            None => LiteralValue::null().as_expression(),
+        }
+    }
+}
+
+impl<T: NonTextExpression> NonTextExpression for Option<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{column::Column, column_def::ColumnDef, eq_sql};
+    use super::*;
+    use crate::{
+        error::Error,
+        winq::{expression_operable::ExpressionOperable, types::ColumnType},
+    };
+
+    #[test]
+    fn test_literal_value() -> Result<()> {
+        eq_sql!(LiteralValue::integer(1), "1");
+        eq_sql!(LiteralValue::unsigned(u64::MAX), "18446744073709551615");
+        eq_sql!(LiteralValue::float(1.5), "1.5");
+        eq_sql!(LiteralValue::bool(true), "TRUE");
+        eq_sql!(LiteralValue::text("it's")?, "'it''s'");
+        eq_sql!(LiteralValue::null(), "NULL");
+        eq_sql!(LiteralValue::current_time(), "CURRENT_TIME");
+        eq_sql!(LiteralValue::current_date(), "CURRENT_DATE");
+        eq_sql!(LiteralValue::current_timestamp(), "CURRENT_TIMESTAMP");
+        Ok(())
+    }
+
+    #[test]
+    fn test_as_literal_value() -> Result<()> {
+        eq_sql!(1i32.as_literal_value()?, "1");
+        eq_sql!(2i64.as_literal_value()?, "2");
+        eq_sql!(3u64.as_literal_value()?, "3");
+        eq_sql!(0.5.as_literal_value()?, "0.5");
+        eq_sql!(false.as_literal_value()?, "FALSE");
+        eq_sql!("x".as_literal_value()?, "'x'");
+        eq_sql!(String::from("x").as_literal_value()?, "'x'");
+        eq_sql!(().as_literal_value()?, "NULL");
+        eq_sql!(Some(1).as_literal_value()?, "1");
+        eq_sql!(None::<i32>.as_literal_value()?, "NULL");
+        Ok(())
+    }
+
+    #[test]
+    fn test_nul_text() -> Result<()> {
+        assert!(matches!(
+            LiteralValue::text("a\0b"),
+            Err(Error::NulError(_))
+        ));
+        assert!(matches!(
+            String::from("a\0b").as_literal_value(),
+            Err(Error::NulError(_))
+        ));
+        eq_sql!(Column::new("a")?.eq("a\0b"), "a == char(97, 0, 98)");
+        Ok(())
+    }
+
+    #[test]
+    fn test_as_expression() -> Result<()> {
+        let a = || Column::new(c"a").unwrap();
+        eq_sql!(a().eq("x"), "a == 'x'");
+        eq_sql!(a().gt(1).and(a().lt(2.5)), "a > 1 AND a < 2.5");
+        eq_sql!(a().is(()), "a IS NULL");
+        eq_sql!(a().eq(Some(String::from("x"))), "a == 'x'");
+        eq_sql!(a().in_values([1, 2, 3]), "a IN(1, 2, 3)");
+        eq_sql!(a().eq(Value::Integer64(4)), "a == 4");
+        eq_sql!(a().eq(Value::Text("y".into())), "a == 'y'");
+        eq_sql!(a().is(Value::Null), "a IS NULL");
+        eq_sql!(a().eq(Value::Blob(vec![0x61, 0, 0xff])), "a == X'6100FF'");
+        eq_sql!(a().eq(Value::Blob(Vec::new())), "a == X''");
+        eq_sql!(
+            ColumnDef::named_with_type(c"a", ColumnType::Integer32)?.default(0),
+            "a INTEGER DEFAULT 0"
+        );
+        eq_sql!(
+            ColumnDef::named_with_type(c"a", ColumnType::Text)?
+                .default(LiteralValue::current_timestamp()),
+            "a TEXT DEFAULT CURRENT_TIMESTAMP"
+        );
+        Ok(())
+    }
+}
@@ -50,7 +50,10 @@ macro_rules! identifier {
     };
 }
 
-use identifier;
+pub(crate) use identifier;
+
+#[cfg(test)]
+pub(crate) use tests::eq_sql;
 
 #[cfg(test)]
 mod tests {
@@ -62,6 +65,3 @@ mod tests {
     }
     pub(crate) use eq_sql;
 }
-
-#[cfg(test)]
-use tests::eq_sql;
@@ -1,5 +1,125 @@
 use libwcdb_sys::CPPOrderingTerm;
 
-use super::identifier;
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsExpression, AsOrderingTerm, IntoName, NonTextExpression},
+        identifier::WithRawIdentifier,
+        types::Order,
+    },
+};
+
+use super::{column::Column, expression::Expression, identifier};
 
 identifier!(OrderingTerm<CPPOrderingTerm>);
+
+impl OrderingTerm {
+    pub fn new<T: AsExpression>(expression: T) -> OrderingTerm {
+        OrderingTerm::new_inner(expression.as_expression())
+    }
+
+    fn new_inner(expression: Expression) -> OrderingTerm {
+        let ptr = expression.with_raw(|e| unsafe { libwcdb_sys::WCDBOrderingTermCreate(e) });
+        OrderingTerm(ptr.into())
+    }
+
+    pub fn order(self, order: Order) -> OrderingTerm {
+        self.with_raw(|t| unsafe { libwcdb_sys::WCDBOrderingTermConfigOrder(t, order.c()) });
+        self
+    }
+
+    pub fn collate<N: IntoName>(self, collation_name: N) -> Result<OrderingTerm> {
+        collation_name.with_name(|name| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBOrderingTermConfigCollation(t, name.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+}
+
+impl AsOrderingTerm for OrderingTerm {
+    fn as_order(self) -> OrderingTerm {
+        self
+    }
+
+    fn order(self, order: Order) -> OrderingTerm {
+        self.order(order)
+    }
+
+    fn collate<N: IntoName>(self, collation_name: N) -> Result<OrderingTerm> {
+        self.collate(collation_name)
+    }
+}
+
+impl<T: NonTextExpression> AsOrderingTerm for T {
+    fn as_order(self) -> OrderingTerm {
+        OrderingTerm::new(self)
+    }
+
+    fn order(self, order: Order) -> OrderingTerm {
+        self.as_order().order(order)
+    }
+
+    fn collate<N: IntoName>(self, collation_name: N) -> Result<OrderingTerm> {
+        self.as_order().collate(collation_name)
+    }
+}
+
+macro_rules! column_name {
+    ($($ty:ty),*) => {
+        $(
+            impl AsOrderingTerm for $ty {
+                fn as_order(self) -> OrderingTerm {
+                    Column::named(self.as_ref()).as_order()
+                }
+
+                fn order(self, order: Order) -> OrderingTerm {
+                    self.as_order().order(order)
+                }
+
+                fn collate<N: IntoName>(self, collation_name: N) -> Result<OrderingTerm> {
+                    self.as_order().collate(collation_name)
+                }
+            }
+        )*
+    };
+}
+
+column_name!(&str, String, &String);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{column::Column, eq_sql, literal_value::LiteralValue};
+    use super::*;
+    use crate::error::Result;
+    use crate::winq::expression_operable::ExpressionOperable;
+
+    #[test]
+    fn test_ordering_term() -> Result<()> {
+        eq_sql!(OrderingTerm::new(Column::new(c"a")?), "a");
+        eq_sql!(Column::new(c"a")?.as_order(), "a");
+        eq_sql!(Column::new(c"a")?.order(Order::Asc), "a ASC");
+        eq_sql!(Column::new(c"a")?.order(Order::Desc), "a DESC");
+        eq_sql!(
+            AsOrderingTerm::collate(Column::new(c"a")?, c"NOCASE")?,
+            "a COLLATE NOCASE"
+        );
+        eq_sql!(
+            OrderingTerm::new(Column::new(c"a")?)
+                .collate(c"NOCASE")?
+                .order(Order::Desc),
+            "a COLLATE NOCASE DESC"
+        );
+        eq_sql!(Column::new(c"a")?.add(1).order(Order::Asc), "a + 1 ASC");
+        eq_sql!(Expression::random().as_order(), "random()");
+        eq_sql!(
+            AsOrderingTerm::collate(LiteralValue::text("b")?, c"NOCASE")?,
+            "'b' COLLATE NOCASE"
+        );
+        eq_sql!("a".as_order(), "a");
+        eq_sql!(String::from("a").order(Order::Desc), "a DESC");
+        eq_sql!(AsOrderingTerm::collate("b", c"NOCASE")?, "b COLLATE NOCASE");
+        Ok(())
+    }
+}
@@ -1,5 +1,102 @@
+use std::ffi::CStr;
+
 use libwcdb_sys::CPPQualifiedTable;
 
-use super::identifier;
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsQualifiedTable, AsSchema, IntoName},
+        identifier::WithRawIdentifier,
+    },
+};
+
+use super::{identifier, schema::Schema};
 
 identifier!(QualifiedTable<CPPQualifiedTable>);
+
+impl QualifiedTable {
+    pub fn new<N: IntoName>(table: N) -> Result<QualifiedTable> {
+        table.with_name(QualifiedTable::new_inner)
+    }
+
+    fn new_inner(table: &CStr) -> QualifiedTable {
+        let ptr = unsafe { libwcdb_sys::WCDBQualifiedTableCreateWithTable(table.as_ptr()) };
+        QualifiedTable(ptr.into())
+    }
+
+    /// `schema.table`
+    pub fn of<T: AsSchema>(self, schema: T) -> Result<QualifiedTable> {
+        Ok(self.of_inner(schema.as_schema()?))
+    }
+
+    fn of_inner(self, schema: Schema) -> QualifiedTable {
+        (&self, &schema)
+            .with_raw(|(t, s)| unsafe { libwcdb_sys::WCDBQualifiedTableConfigSchema(t, s) });
+        self
+    }
+
+    /// `table AS alias`
+    pub fn r#as<N: IntoName>(self, alias: N) -> Result<QualifiedTable> {
+        alias.with_name(|alias| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBQualifiedTableConfigAliasName(t, alias.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// `table INDEXED BY index`
+    pub fn indexed_by<N: IntoName>(self, index: N) -> Result<QualifiedTable> {
+        index.with_name(|index| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBQualifiedTableConfigIndexName(t, index.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// `table NOT INDEXED`
+    pub fn not_indexed(self) -> QualifiedTable {
+        self.with_raw(|t| unsafe { libwcdb_sys::WCDBQualifiedTableConfigNoIndexed(t) });
+        self
+    }
+}
+
+impl AsQualifiedTable for QualifiedTable {
+    fn as_qualified_table(self) -> Result<QualifiedTable> {
+        Ok(self)
+    }
+}
+
+impl<N: IntoName> AsQualifiedTable for N {
+    fn as_qualified_table(self) -> Result<QualifiedTable> {
+        QualifiedTable::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::eq_sql;
+    use super::*;
+    use crate::error::{Error, Result};
+
+    #[test]
+    fn test_qualified_table() -> Result<()> {
+        eq_sql!(QualifiedTable::new(c"t")?, "t");
+        eq_sql!(QualifiedTable::new(String::from("t"))?, "t");
+        eq_sql!(QualifiedTable::new(c"t")?.of("s")?, "s.t");
+        eq_sql!(QualifiedTable::new(c"t")?.of(Schema::main())?, "main.t");
+        eq_sql!(QualifiedTable::new(c"t")?.r#as(c"u")?, "t AS u");
+        eq_sql!(
+            QualifiedTable::new(c"t")?.indexed_by(c"i")?,
+            "t INDEXED BY i"
+        );
+        eq_sql!(QualifiedTable::new(c"t")?.not_indexed(), "t NOT INDEXED");
+        eq_sql!(QualifiedTable::new("t")?.of(String::from("s"))?, "s.t");
+        assert!(matches!(
+            QualifiedTable::new("t")?.of("s\0"),
+            Err(Error::NulError(_))
+        ));
+        Ok(())
+    }
+}
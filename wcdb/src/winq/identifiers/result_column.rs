@@ -1,5 +1,92 @@
 use libwcdb_sys::CPPResultColumn;
 
-use super::identifier;
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsExpression, AsResultColumn, IntoName, NonTextExpression},
+        identifier::WithRawIdentifier,
+    },
+};
+
+use super::{column::Column, expression::Expression, identifier};
 
 identifier!(ResultColumn<CPPResultColumn>);
+
+impl ResultColumn {
+    pub fn new<T: AsExpression>(expression: T) -> ResultColumn {
+        ResultColumn::new_inner(expression.as_expression())
+    }
+
+    fn new_inner(expression: Expression) -> ResultColumn {
+        let ptr = expression
+            .with_raw(|e| unsafe { libwcdb_sys::WCDBResultColumnCreateWithExpression(e) });
+        ResultColumn(ptr.into())
+    }
+
+    pub fn r#as<N: IntoName>(self, alias: N) -> Result<ResultColumn> {
+        alias.with_name(|alias| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBResultColumnConfigAlias(t, alias.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+}
+
+impl AsResultColumn for ResultColumn {
+    fn as_result_column(self) -> ResultColumn {
+        self
+    }
+}
+
+impl<T: NonTextExpression> AsResultColumn for T {
+    fn as_result_column(self) -> ResultColumn {
+        ResultColumn::new(self)
+    }
+}
+
+macro_rules! column_name {
+    ($($ty:ty),*) => {
+        $(
+            impl AsResultColumn for $ty {
+                fn as_result_column(self) -> ResultColumn {
+                    Column::named(self.as_ref()).as_result_column()
+                }
+            }
+        )*
+    };
+}
+
+column_name!(&str, String, &String);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{eq_sql, literal_value::LiteralValue};
+    use super::*;
+    use crate::error::Result;
+    use crate::winq::expression_operable::ExpressionOperable;
+
+    #[test]
+    fn test_result_column() -> Result<()> {
+        eq_sql!(ResultColumn::new(Column::new(c"a")?), "a");
+        eq_sql!(Column::all().as_result_column(), "*");
+        eq_sql!(Column::new(c"a")?.as_result_column().r#as(c"b")?, "a AS b");
+        eq_sql!(Column::new(c"a")?.r#as(c"b")?, "a AS b");
+        eq_sql!(
+            Column::new(c"a")?.add(1).as_result_column().r#as(c"b")?,
+            "a + 1 AS b"
+        );
+        eq_sql!(Expression::count_all().r#as(c"c")?, "count(*) AS c");
+        eq_sql!(1.as_result_column(), "1");
+        eq_sql!(LiteralValue::text("a")?.as_result_column(), "'a'");
+        eq_sql!("a".as_result_column(), "a");
+        eq_sql!(String::from("a").as_result_column(), "a");
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nul_column_name() {
+        "a\0b".as_result_column();
+    }
+}
@@ -1,5 +1,65 @@
+use std::ffi::CStr;
+
 use libwcdb_sys::CPPSchema;
 
+use crate::{
+    error::Result,
+    winq::convert::{AsSchema, IntoName},
+};
+
 use super::identifier;
 
 identifier!(Schema<CPPSchema>);
+
+impl Schema {
+    pub fn new<N: IntoName>(name: N) -> Result<Schema> {
+        name.with_name(Schema::new_inner)
+    }
+
+    fn new_inner(name: &CStr) -> Schema {
+        let ptr = unsafe { libwcdb_sys::WCDBSchemaCreateWithName(name.as_ptr()) };
+        Schema(ptr.into())
+    }
+
+    pub fn main() -> Schema {
+        let ptr = unsafe { libwcdb_sys::WCDBSchemaMain() };
+        Schema(ptr.into())
+    }
+
+    pub fn temp() -> Schema {
+        let ptr = unsafe { libwcdb_sys::WCDBSchemaTemp() };
+        Schema(ptr.into())
+    }
+}
+
+impl AsSchema for Schema {
+    fn as_schema(self) -> Result<Schema> {
+        Ok(self)
+    }
+}
+
+impl<N: IntoName> AsSchema for N {
+    fn as_schema(self) -> Result<Schema> {
+        Schema::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{column::Column, eq_sql};
+    use super::*;
+    use crate::error::Result;
+
+    #[test]
+    fn test_schema() -> Result<()> {
+        eq_sql!(Schema::new(c"s")?, "s");
+        eq_sql!(Schema::main(), "main");
+        eq_sql!(Schema::temp(), "temp");
+        eq_sql!(Schema::new(String::from("s"))?, "s");
+        eq_sql!(
+            Column::new(c"a")?.r#in(c"t")?.of(Schema::new("s")?)?,
+            "s.t.a"
+        );
+        Ok(())
+    }
+}
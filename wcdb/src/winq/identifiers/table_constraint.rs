@@ -1,5 +1,143 @@
+use std::ptr;
+
 use libwcdb_sys::CPPTableConstraint;
 
-use super::identifier;
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsColumn, AsExpression, AsIndexedColumn, IntoName},
+        identifier::WithRawIdentifier,
+        types::Conflict,
+    },
+};
+
+use super::{
+    column::Column, expression::Expression, foreign_key::ForeignKey, identifier,
+    indexed_column::IndexedColumn,
+};
 
 identifier!(TableConstraint<CPPTableConstraint>);
+
+impl TableConstraint {
+    pub fn new() -> TableConstraint {
+        let ptr = unsafe { libwcdb_sys::WCDBTableConstraintCreate(ptr::null()) };
+        TableConstraint(ptr.into())
+    }
+
+    /// `CONSTRAINT name`
+    pub fn named<N: IntoName>(name: N) -> Result<TableConstraint> {
+        let ptr = name
+            .with_name(|name| unsafe { libwcdb_sys::WCDBTableConstraintCreate(name.as_ptr()) })?;
+        Ok(TableConstraint(ptr.into()))
+    }
+
+    /// `PRIMARY KEY`, whose columns follow `indexed_by`.
+    pub fn primary_key(self) -> TableConstraint {
+        self.with_raw(|t| unsafe { libwcdb_sys::WCDBTableConstraintConfigPrimaryKey(t) });
+        self
+    }
+
+    /// `UNIQUE`, whose columns follow `indexed_by`.
+    pub fn unique(self) -> TableConstraint {
+        self.with_raw(|t| unsafe { libwcdb_sys::WCDBTableConstraintConfigUnique(t) });
+        self
+    }
+
+    /// `(indexed_column, ...)` of `PRIMARY KEY` or `UNIQUE`.
+    pub fn indexed_by<T, I>(self, columns: I) -> TableConstraint
+    where
+        T: AsIndexedColumn,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.as_index()).collect();
+        self.indexed_by_inner(columns)
+    }
+
+    fn indexed_by_inner(self, columns: Vec<IndexedColumn>) -> TableConstraint {
+        (&self, &columns).with_raw(|(t, c)| unsafe {
+            libwcdb_sys::WCDBTableConstraintConfigIndexedColumn(t, c.as_ptr(), c.len() as _)
+        });
+        self
+    }
+
+    /// `ON CONFLICT conflict` of `PRIMARY KEY` or `UNIQUE`.
+    pub fn conflict(self, conflict: Conflict) -> TableConstraint {
+        self.with_raw(|t| unsafe {
+            libwcdb_sys::WCDBTableConstraintConfigConfliction(t, conflict.c())
+        });
+        self
+    }
+
+    /// `CHECK (condition)`
+    pub fn check<T: AsExpression>(self, condition: T) -> TableConstraint {
+        self.check_inner(condition.as_expression())
+    }
+
+    fn check_inner(self, condition: Expression) -> TableConstraint {
+        (&self, &condition).with_raw(|(t, c)| unsafe {
+            libwcdb_sys::WCDBTableConstraintConfigCheckCondition(t, c)
+        });
+        self
+    }
+
+    /// `FOREIGN KEY (column, ...) foreign_key`
+    pub fn foreign_key<T, I>(self, columns: I, foreign_key: ForeignKey) -> TableConstraint
+    where
+        T: AsColumn,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.as_column()).collect();
+        self.foreign_key_inner(columns, foreign_key)
+    }
+
+    fn foreign_key_inner(self, columns: Vec<Column>, foreign_key: ForeignKey) -> TableConstraint {
+        (&self, (&columns, &foreign_key)).with_raw(|(t, (c, f))| unsafe {
+            libwcdb_sys::WCDBTableConstraintConfigForeignKey(t, c.as_ptr(), c.len() as _, f)
+        });
+        self
+    }
+}
+
+impl Default for TableConstraint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::eq_sql;
+    use super::*;
+    use crate::winq::expression_operable::ExpressionOperable;
+
+    #[test]
+    fn test_table_constraint() -> Result<()> {
+        eq_sql!(
+            TableConstraint::new()
+                .primary_key()
+                .indexed_by([Column::new("a")?, Column::new("b")?]),
+            "PRIMARY KEY(a, b)"
+        );
+        eq_sql!(
+            TableConstraint::named("u")?
+                .unique()
+                .indexed_by([Column::new("a")?])
+                .conflict(Conflict::Replace),
+            "CONSTRAINT u UNIQUE(a) ON CONFLICT REPLACE"
+        );
+        eq_sql!(
+            TableConstraint::new().check(Column::new("a")?.gt(0)),
+            "CHECK(a > 0)"
+        );
+        eq_sql!(
+            TableConstraint::new().foreign_key(
+                [Column::new("a")?],
+                ForeignKey::new()
+                    .references("t")?
+                    .columns([Column::new("b")?])
+            ),
+            "FOREIGN KEY(a) REFERENCES t(b)"
+        );
+        Ok(())
+    }
+}
@@ -1,5 +1,239 @@
+use std::ffi::CStr;
+
 use libwcdb_sys::CPPTableOrSubquery;
 
-use super::identifier;
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsExpression, AsSchema, AsSelect, AsTableOrSubquery, IntoName},
+        identifier::WithRawIdentifier,
+        statements::select::StatementSelect,
+    },
+};
+
+use super::{column::Column, expression::Expression, identifier, schema::Schema};
 
 identifier!(TableOrSubquery<CPPTableOrSubquery>);
+
+impl TableOrSubquery {
+    pub fn new<N: IntoName>(table: N) -> Result<TableOrSubquery> {
+        table.with_name(TableOrSubquery::new_inner)
+    }
+
+    fn new_inner(table: &CStr) -> TableOrSubquery {
+        let ptr = unsafe { libwcdb_sys::WCDBTableOrSubqueryCreateWithTable(table.as_ptr()) };
+        TableOrSubquery(ptr.into())
+    }
+
+    /// A table-valued function, e.g. `json_each(...)` or `pragma_table_info(...)`.
+    /// Use `argument` or `arguments` to pass arguments to the function.
+    pub fn function<N: IntoName>(name: N) -> Result<TableOrSubquery> {
+        let ptr = name.with_name(|name| unsafe {
+            libwcdb_sys::WCDBTableOrSubqueryCreateWithFunction(name.as_ptr())
+        })?;
+        Ok(TableOrSubquery(ptr.into()))
+    }
+
+    /// A parenthesised select, `(SELECT ...)`.
+    pub fn subquery<S: AsSelect>(select: S) -> TableOrSubquery {
+        let ptr =
+            select.with_raw(|s| unsafe { libwcdb_sys::WCDBTableOrSubqueryCreateWithSelection(s) });
+        TableOrSubquery(ptr.into())
+    }
+
+    /// A parenthesised list of tables, `(table, ...)`.
+    pub fn tables<T, I>(tables: I) -> Result<TableOrSubquery>
+    where
+        T: AsTableOrSubquery,
+        I: IntoIterator<Item = T>,
+    {
+        let tables = tables
+            .into_iter()
+            .map(|t| t.as_table_or_subquery())
+            .collect::<Result<_>>()?;
+        Ok(TableOrSubquery::tables_inner(tables))
+    }
+
+    fn tables_inner(tables: Vec<TableOrSubquery>) -> TableOrSubquery {
+        let ptr = tables.with_raw(|t| unsafe {
+            libwcdb_sys::WCDBTableOrSubqueryCreateWithTableOrSubqueries(t.as_ptr(), t.len() as _)
+        });
+        TableOrSubquery(ptr.into())
+    }
+
+    /// `schema.table`
+    pub fn of<T: AsSchema>(self, schema: T) -> Result<TableOrSubquery> {
+        Ok(self.of_inner(schema.as_schema()?))
+    }
+
+    fn of_inner(self, schema: Schema) -> TableOrSubquery {
+        (&self, &schema)
+            .with_raw(|(t, s)| unsafe { libwcdb_sys::WCDBTableOrSubqueryConfigSchema(t, s) });
+        self
+    }
+
+    /// `table AS alias`
+    pub fn r#as<N: IntoName>(self, alias: N) -> Result<TableOrSubquery> {
+        alias.with_name(|alias| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBTableOrSubqueryConfigAs(t, alias.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// `table INDEXED BY index`
+    pub fn indexed_by<N: IntoName>(self, index: N) -> Result<TableOrSubquery> {
+        index.with_name(|index| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBTableOrSubqueryConfigIndexName(t, index.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// `table NOT INDEXED`
+    pub fn not_indexed(self) -> TableOrSubquery {
+        self.with_raw(|t| unsafe { libwcdb_sys::WCDBTableOrSubqueryConfigNotIndexed(t) });
+        self
+    }
+
+    /// Append an argument to the table-valued function.
+    pub fn argument<T: AsExpression>(self, argument: T) -> TableOrSubquery {
+        self.argument_inner(argument.as_expression())
+    }
+
+    fn argument_inner(self, argument: Expression) -> TableOrSubquery {
+        (&self, &argument).with_raw(|(t, a)| unsafe {
+            let value = libwcdb_sys::CPPCommonValue {
+                type_: libwcdb_sys::WCDBBridgedType_WCDBBridgedType_Expression,
+                __bindgen_anon_1: libwcdb_sys::CPPCommonValue__bindgen_ty_1 {
+                    intValue: a.innerValue as _,
+                },
+            };
+            libwcdb_sys::WCDBTableOrSubqueryConfigArgument(t, value)
+        });
+        self
+    }
+
+    /// Replace all arguments of the table-valued function.
+    pub fn arguments<T, I>(self, arguments: I) -> TableOrSubquery
+    where
+        T: AsExpression,
+        I: IntoIterator<Item = T>,
+    {
+        let arguments = arguments.into_iter().map(|a| a.as_expression()).collect();
+        self.arguments_inner(arguments)
+    }
+
+    fn arguments_inner(self, arguments: Vec<Expression>) -> TableOrSubquery {
+        (&self, &arguments).with_raw(|(t, a)| unsafe {
+            libwcdb_sys::WCDBTableOrSubqueryConfigArguments(t, a.as_ptr(), a.len() as _)
+        });
+        self
+    }
+}
+
+impl AsTableOrSubquery for TableOrSubquery {
+    fn as_table_or_subquery(self) -> Result<TableOrSubquery> {
+        Ok(self)
+    }
+}
+
+impl<N: IntoName> AsTableOrSubquery for N {
+    fn as_table_or_subquery(self) -> Result<TableOrSubquery> {
+        TableOrSubquery::new(self)
+    }
+}
+
+impl AsTableOrSubquery for Column {
+    fn as_table_or_subquery(self) -> Result<TableOrSubquery> {
+        let ptr = self.with_raw(|c| unsafe {
+            let value = libwcdb_sys::CPPCommonValue {
+                type_: libwcdb_sys::WCDBBridgedType_WCDBBridgedType_Column,
+                __bindgen_anon_1: libwcdb_sys::CPPCommonValue__bindgen_ty_1 {
+                    intValue: c.innerValue as _,
+                },
+            };
+            libwcdb_sys::WCDBTableOrSubqueryCreate(value)
+        });
+        Ok(TableOrSubquery(ptr.into()))
+    }
+}
+
+impl AsTableOrSubquery for StatementSelect {
+    fn as_table_or_subquery(self) -> Result<TableOrSubquery> {
+        Ok(TableOrSubquery::subquery(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{eq_sql, join::Join};
+    use super::*;
+    use crate::error::{Error, Result};
+
+    #[test]
+    fn test_table_or_subquery() -> Result<()> {
+        eq_sql!(TableOrSubquery::new(c"t")?, "t");
+        eq_sql!(TableOrSubquery::new(String::from("t"))?, "t");
+        eq_sql!(Column::new(c"t")?.as_table_or_subquery()?, "t");
+        eq_sql!(TableOrSubquery::new(c"t")?.of("s")?, "s.t");
+        eq_sql!(TableOrSubquery::new(c"t")?.r#as(c"u")?, "t AS u");
+        eq_sql!(TableOrSubquery::new(c"t")?.of("s")?.r#as(c"u")?, "s.t AS u");
+        eq_sql!(
+            TableOrSubquery::new(c"t")?.indexed_by(c"i")?,
+            "t INDEXED BY i"
+        );
+        eq_sql!(TableOrSubquery::new(c"t")?.not_indexed(), "t NOT INDEXED");
+        eq_sql!(
+            StatementSelect::new()
+                .select([Column::new(c"a")?])
+                .from(["t"])?
+                .as_table_or_subquery()?
+                .r#as(c"u")?,
+            "(SELECT a FROM t) AS u"
+        );
+        eq_sql!(TableOrSubquery::tables(["a", "b"])?, "(a, b)");
+        eq_sql!(
+            TableOrSubquery::tables([String::from("a"), String::from("b")])?,
+            "(a, b)"
+        );
+        eq_sql!(
+            TableOrSubquery::tables([Join::new("a")?.join("b")?])?,
+            "(a JOIN b)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_nul_table() {
+        assert!(matches!(
+            TableOrSubquery::tables(["a", "b\0"]),
+            Err(Error::NulError(_))
+        ));
+        assert!(matches!(
+            StatementSelect::new().from(["t\0"]),
+            Err(Error::NulError(_))
+        ));
+    }
+
+    #[test]
+    fn test_table_function() -> Result<()> {
+        eq_sql!(
+            TableOrSubquery::function(c"json_each")?.argument(Column::new(c"a")?),
+            "json_each(a)"
+        );
+        eq_sql!(
+            TableOrSubquery::function(c"pragma_table_info")?.argument(Expression::text(c"t")?),
+            "pragma_table_info('t')"
+        );
+        eq_sql!(
+            TableOrSubquery::function(c"generate_series")?
+                .arguments([Expression::integer(1), Expression::integer(10)])
+                .r#as(c"s")?,
+            "generate_series(1, 10) AS s"
+        );
+        Ok(())
+    }
+}
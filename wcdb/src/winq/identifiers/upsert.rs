@@ -1,5 +1,143 @@
 use libwcdb_sys::CPPUpsert;
 
-use super::identifier;
+use crate::winq::{
+    convert::{AsColumn, AsExpression, AsIndexedColumn},
+    identifier::WithRawIdentifier,
+};
+
+use super::{column::Column, expression::Expression, identifier, indexed_column::IndexedColumn};
 
 identifier!(Upsert<CPPUpsert>);
+
+impl Upsert {
+    /// `ON CONFLICT`, without a conflict target.
+    pub fn new() -> Upsert {
+        let ptr = unsafe { libwcdb_sys::WCDBUpsertCreate() };
+        Upsert(ptr.into())
+    }
+
+    /// `ON CONFLICT (indexed_column, ...)`
+    pub fn on_conflict<T, I>(self, columns: I) -> Upsert
+    where
+        T: AsIndexedColumn,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.as_index()).collect();
+        self.on_conflict_inner(columns)
+    }
+
+    fn on_conflict_inner(self, columns: Vec<IndexedColumn>) -> Upsert {
+        (&self, &columns).with_raw(|(u, c)| unsafe {
+            libwcdb_sys::WCDBUpsertConfigIndexdColumn(u, c.as_ptr(), c.len() as _)
+        });
+        self
+    }
+
+    /// `WHERE condition`. Before `do_update`, it filters the conflict target;
+    /// after that, it filters the rows to update.
+    pub fn r#where<T: AsExpression>(self, condition: T) -> Upsert {
+        self.where_inner(condition.as_expression())
+    }
+
+    fn where_inner(self, condition: Expression) -> Upsert {
+        (&self, &condition).with_raw(|(u, c)| unsafe { libwcdb_sys::WCDBUpsertConfigWhere(u, c) });
+        self
+    }
+
+    /// `DO NOTHING`
+    pub fn do_nothing(self) -> Upsert {
+        self.with_raw(|u| unsafe { libwcdb_sys::WCDBUpsertConfigDoNothing(u) });
+        self
+    }
+
+    /// `DO UPDATE`, followed by `set(..).to(..)` pairs.
+    pub fn do_update(self) -> Upsert {
+        self.with_raw(|u| unsafe { libwcdb_sys::WCDBUpsertConfigDoUpdate(u) });
+        self
+    }
+
+    /// `SET column` or `SET (column, ...)`, whose value is given by `to`.
+    pub fn set<T, I>(self, columns: I) -> Upsert
+    where
+        T: AsColumn,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.as_column()).collect();
+        self.set_inner(columns)
+    }
+
+    fn set_inner(self, columns: Vec<Column>) -> Upsert {
+        (&self, &columns).with_raw(|(u, c)| unsafe {
+            libwcdb_sys::WCDBUpsertConfigSetColumns(u, c.as_ptr(), c.len() as _)
+        });
+        self
+    }
+
+    /// `= value`
+    pub fn to<T: AsExpression>(self, value: T) -> Upsert {
+        self.value_inner(value.as_expression())
+    }
+
+    fn value_inner(self, value: Expression) -> Upsert {
+        (&self, &value).with_raw(|(u, v)| unsafe { libwcdb_sys::WCDBUpsertConfigToValue(u, v) });
+        self
+    }
+}
+
+impl Default for Upsert {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::eq_sql;
+    use super::*;
+    use crate::error::Result;
+    use crate::winq::{
+        expression_operable::ExpressionOperable, statements::select::StatementSelect,
+    };
+
+    fn excluded(name: &std::ffi::CStr) -> Column {
+        Column::new(name).unwrap().r#in(c"excluded").unwrap()
+    }
+
+    #[test]
+    fn test_upsert() -> Result<()> {
+        eq_sql!(Upsert::new().do_nothing(), "ON CONFLICT DO NOTHING");
+        eq_sql!(
+            Upsert::new().on_conflict([Column::new(c"a")?]).do_nothing(),
+            "ON CONFLICT(a) DO NOTHING"
+        );
+        eq_sql!(
+            Upsert::new()
+                .on_conflict([Column::new(c"a")?])
+                .r#where(Column::new(c"b")?.not_null())
+                .do_nothing(),
+            "ON CONFLICT(a) WHERE b NOTNULL DO NOTHING"
+        );
+        eq_sql!(
+            Upsert::new()
+                .on_conflict([Column::new(c"a")?])
+                .do_update()
+                .set([Column::new(c"b")?])
+                .to(excluded(c"b"))
+                .set([Column::new(c"c")?])
+                .to(excluded(c"c"))
+                .r#where(Column::new(c"d")?.lt(excluded(c"d"))),
+            "ON CONFLICT(a) DO UPDATE SET b = excluded.b, c = excluded.c WHERE d < excluded.d"
+        );
+        eq_sql!(
+            Upsert::new()
+                .on_conflict([Column::new(c"a")?])
+                .do_update()
+                .set([Column::new(c"b")?, Column::new(c"c")?])
+                .to(StatementSelect::new()
+                    .select([Column::new(c"b")?, Column::new(c"c")?])
+                    .from(["t"])?),
+            "ON CONFLICT(a) DO UPDATE SET (b, c) = (SELECT b, c FROM t)"
+        );
+        Ok(())
+    }
+}
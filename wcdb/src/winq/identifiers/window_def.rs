@@ -1,5 +1,93 @@
 use libwcdb_sys::CPPWindowDef;
 
-use super::identifier;
+use crate::winq::{
+    convert::{AsExpression, AsOrderingTerm},
+    identifier::WithRawIdentifier,
+};
+
+use super::{
+    expression::Expression, frame_spec::FrameSpec, identifier, ordering_term::OrderingTerm,
+};
 
 identifier!(WindowDef<CPPWindowDef>);
+
+impl WindowDef {
+    pub fn new() -> WindowDef {
+        let ptr = unsafe { libwcdb_sys::WCDBWindowDefCreate() };
+        WindowDef(ptr.into())
+    }
+
+    /// `PARTITION BY expression, ...`
+    pub fn partition<T, I>(self, expressions: I) -> WindowDef
+    where
+        T: AsExpression,
+        I: IntoIterator<Item = T>,
+    {
+        let expressions = expressions.into_iter().map(|e| e.as_expression()).collect();
+        self.partition_inner(expressions)
+    }
+
+    fn partition_inner(self, expressions: Vec<Expression>) -> WindowDef {
+        (&self, &expressions).with_raw(|(t, e)| unsafe {
+            libwcdb_sys::WCDBWindowDefConfigPartition(t, e.as_ptr(), e.len() as _)
+        });
+        self
+    }
+
+    /// `ORDER BY ordering_term, ...`
+    pub fn order<T, I>(self, orders: I) -> WindowDef
+    where
+        T: AsOrderingTerm,
+        I: IntoIterator<Item = T>,
+    {
+        let orders = orders.into_iter().map(|o| o.as_order()).collect();
+        self.order_inner(orders)
+    }
+
+    fn order_inner(self, orders: Vec<OrderingTerm>) -> WindowDef {
+        (&self, &orders).with_raw(|(t, o)| unsafe {
+            libwcdb_sys::WCDBWindowDefConfigOrder(t, o.as_ptr(), o.len() as _)
+        });
+        self
+    }
+
+    pub fn frame_spec(self, frame_spec: FrameSpec) -> WindowDef {
+        (&self, &frame_spec)
+            .with_raw(|(t, f)| unsafe { libwcdb_sys::WCDBWindowDefConfigFrameSpec(t, f) });
+        self
+    }
+}
+
+impl Default for WindowDef {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{column::Column, eq_sql};
+    use super::*;
+    use crate::error::Result;
+    use crate::winq::types::Order;
+
+    #[test]
+    fn test_window_def() -> Result<()> {
+        eq_sql!(
+            WindowDef::new().partition([Column::new(c"a")?]),
+            "(PARTITION BY a)"
+        );
+        eq_sql!(
+            WindowDef::new().order([Column::new(c"b")?.order(Order::Desc)]),
+            "(ORDER BY b DESC)"
+        );
+        eq_sql!(
+            WindowDef::new()
+                .partition([Column::new(c"a")?, Column::new(c"b")?])
+                .order([Column::new(c"c")?])
+                .frame_spec(FrameSpec::rows().unbounded_preceding()),
+            "(PARTITION BY a, b ORDER BY c ROWS UNBOUNDED PRECEDING)"
+        );
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 pub mod convert;
+pub mod expression_operable;
 pub mod identifier;
 pub mod identifiers;
 pub mod statement;
+pub mod statements;
 pub mod types;
 pub mod value;
 
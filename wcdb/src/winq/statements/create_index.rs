@@ -0,0 +1,135 @@
+use libwcdb_sys::CPPStatementCreateIndex;
+
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsExpression, AsIndexedColumn, AsSchema, IntoName},
+        identifier::WithRawIdentifier,
+        identifiers::{expression::Expression, indexed_column::IndexedColumn, schema::Schema},
+    },
+};
+
+use super::statement;
+
+statement!(StatementCreateIndex<CPPStatementCreateIndex>);
+
+impl StatementCreateIndex {
+    pub fn new() -> StatementCreateIndex {
+        let ptr = unsafe { libwcdb_sys::WCDBStatementCreateIndexCreate() };
+        StatementCreateIndex(ptr.into())
+    }
+
+    /// `CREATE INDEX name`
+    pub fn create_index<N: IntoName>(self, name: N) -> Result<StatementCreateIndex> {
+        name.with_name(|name| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBStatementCreateIndexConfigIndexName(t, name.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// `CREATE INDEX schema.name`
+    pub fn of<T: AsSchema>(self, schema: T) -> Result<StatementCreateIndex> {
+        Ok(self.of_inner(schema.as_schema()?))
+    }
+
+    fn of_inner(self, schema: Schema) -> StatementCreateIndex {
+        (&self, &schema)
+            .with_raw(|(t, s)| unsafe { libwcdb_sys::WCDBStatementCreateIndexConfigSchema(t, s) });
+        self
+    }
+
+    /// `CREATE UNIQUE INDEX`
+    pub fn unique(self) -> StatementCreateIndex {
+        self.with_raw(|t| unsafe { libwcdb_sys::WCDBStatementCreateIndexConfigUniqe(t) });
+        self
+    }
+
+    /// `CREATE INDEX IF NOT EXISTS`
+    pub fn if_not_exist(self) -> StatementCreateIndex {
+        self.with_raw(|t| unsafe { libwcdb_sys::WCDBStatementCreateIndexConfigIfNotExist(t) });
+        self
+    }
+
+    /// `ON table`
+    pub fn on<N: IntoName>(self, table: N) -> Result<StatementCreateIndex> {
+        table.with_name(|table| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBStatementCreateIndexConfigTable(t, table.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// `(indexed_column, ...)`
+    pub fn indexed_by<T, I>(self, columns: I) -> StatementCreateIndex
+    where
+        T: AsIndexedColumn,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.as_index()).collect();
+        self.indexed_by_inner(columns)
+    }
+
+    fn indexed_by_inner(self, columns: Vec<IndexedColumn>) -> StatementCreateIndex {
+        (&self, &columns).with_raw(|(t, c)| unsafe {
+            libwcdb_sys::WCDBStatementCreateIndexConfigIndexColumns(t, c.as_ptr(), c.len() as _)
+        });
+        self
+    }
+
+    /// `WHERE condition`, which makes a partial index.
+    pub fn r#where<T: AsExpression>(self, condition: T) -> StatementCreateIndex {
+        self.where_inner(condition.as_expression())
+    }
+
+    fn where_inner(self, condition: Expression) -> StatementCreateIndex {
+        (&self, &condition)
+            .with_raw(|(t, c)| unsafe { libwcdb_sys::WCDBStatementCreateIndexConfigWhere(t, c) });
+        self
+    }
+}
+
+impl Default for StatementCreateIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::winq::{
+        convert::AsOrderedIndexedColumn,
+        expression_operable::ExpressionOperable,
+        identifiers::{column::Column, eq_sql},
+        types::Order,
+    };
+
+    #[test]
+    fn test_create_index() -> Result<()> {
+        eq_sql!(
+            StatementCreateIndex::new()
+                .create_index("i")?
+                .on("t")?
+                .indexed_by([Column::new("a")?]),
+            "CREATE INDEX i ON t(a)"
+        );
+        eq_sql!(
+            StatementCreateIndex::new()
+                .create_index("i")?
+                .of(Schema::new("s")?)?
+                .unique()
+                .if_not_exist()
+                .on("t")?
+                .indexed_by([
+                    Column::new("a")?.as_ordered_index(Order::Desc),
+                    Column::new("b")?.as_index()
+                ])
+                .r#where(Column::new("a")?.not_null()),
+            "CREATE UNIQUE INDEX IF NOT EXISTS s.i ON t(a DESC, b) WHERE a NOTNULL"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use libwcdb_sys::CPPStatementDelete;
+
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsExpression, AsOrderingTerm, AsQualifiedTable},
+        identifier::WithRawIdentifier,
+        identifiers::{
+            expression::Expression, ordering_term::OrderingTerm, qualified_table::QualifiedTable,
+        },
+    },
+};
+
+use super::{limit, limited, statement, with};
+
+statement!(StatementDelete<CPPStatementDelete>);
+
+impl StatementDelete {
+    pub fn new() -> StatementDelete {
+        let ptr = unsafe { libwcdb_sys::WCDBStatementDeleteCreate() };
+        StatementDelete(ptr.into())
+    }
+
+    with!(
+        WCDBStatementDeleteConfigWith,
+        WCDBStatementDeleteConfigRecursive
+    );
+
+    /// `DELETE FROM qualified_table`
+    pub fn delete_from<T: AsQualifiedTable>(self, table: T) -> Result<StatementDelete> {
+        Ok(self.delete_from_inner(table.as_qualified_table()?))
+    }
+
+    fn delete_from_inner(self, table: QualifiedTable) -> StatementDelete {
+        (&self, &table)
+            .with_raw(|(t, q)| unsafe { libwcdb_sys::WCDBStatementDeleteConfigDeleteFrom(t, q) });
+        self
+    }
+
+    /// `WHERE condition`
+    pub fn r#where<T: AsExpression>(self, condition: T) -> StatementDelete {
+        self.where_inner(condition.as_expression())
+    }
+
+    fn where_inner(self, condition: Expression) -> StatementDelete {
+        (&self, &condition)
+            .with_raw(|(t, c)| unsafe { libwcdb_sys::WCDBStatementDeleteConfigWhere(t, c) });
+        self
+    }
+
+    /// `ORDER BY ordering_term, ...`
+    pub fn order_by<T, I>(self, orders: I) -> StatementDelete
+    where
+        T: AsOrderingTerm,
+        I: IntoIterator<Item = T>,
+    {
+        let orders = orders.into_iter().map(|o| o.as_order()).collect();
+        self.order_by_inner(orders)
+    }
+
+    fn order_by_inner(self, orders: Vec<OrderingTerm>) -> StatementDelete {
+        (&self, &orders).with_raw(|(t, o)| unsafe {
+            libwcdb_sys::WCDBStatementDeleteConfigOrder(t, o.as_ptr(), o.len() as _)
+        });
+        self
+    }
+
+    limit!(LimitedDelete);
+
+    fn limit_inner(self, limit: Expression) -> StatementDelete {
+        (&self, &limit)
+            .with_raw(|(t, l)| unsafe { libwcdb_sys::WCDBStatementDeleteConfigLimitCount(t, l) });
+        self
+    }
+
+    fn offset_inner(self, offset: Expression) -> StatementDelete {
+        (&self, &offset)
+            .with_raw(|(t, o)| unsafe { libwcdb_sys::WCDBStatementDeleteConfigOffset(t, o) });
+        self
+    }
+}
+
+limited!(
+    /// A delete finished by `limit` or `offset`.
+    LimitedDelete<StatementDelete>
+);
+
+impl Default for StatementDelete {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::winq::{
+        expression_operable::ExpressionOperable,
+        identifiers::{
+            column::Column, common_table_expression::CommonTableExpression, eq_sql, join::Join,
+        },
+        statements::select::StatementSelect,
+    };
+
+    #[test]
+    fn test_delete() -> Result<()> {
+        eq_sql!(StatementDelete::new().delete_from("t")?, "DELETE FROM t");
+        eq_sql!(
+            StatementDelete::new()
+                .delete_from("t")?
+                .r#where(Column::new(c"a")?.eq(Expression::integer(1)))
+                .order_by([Column::new(c"b")?])
+                .limit(Expression::integer(1))
+                .offset(Expression::integer(2)),
+            "DELETE FROM t WHERE a == 1 ORDER BY b LIMIT 1 OFFSET 2"
+        );
+        eq_sql!(
+            StatementDelete::new()
+                .delete_from("t")?
+                .offset(Expression::integer(2)),
+            "DELETE FROM t LIMIT -1 OFFSET 2"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_with() -> Result<()> {
+        let id = || Column::new(c"id").unwrap();
+        // The row 1 and all its descendants.
+        let descendants = CommonTableExpression::new(c"c")?.column(id()).r#as(
+            StatementSelect::new()
+                .select([Expression::integer(1)])
+                .union_all()
+                .select([id().r#in(c"t")?])
+                .from([Join::new("t")?
+                    .join("c")?
+                    .on(Column::new(c"parent")?.r#in(c"t")?.eq(id().r#in(c"c")?))])?,
+        );
+        eq_sql!(
+            StatementDelete::new()
+                .with_recursive([descendants])
+                .delete_from("t")?
+                .r#where(id().in_table(c"c")?),
+            "WITH RECURSIVE c(id) AS(SELECT 1 UNION ALL SELECT t.id FROM t JOIN c ON t.parent == c.id) DELETE FROM t WHERE id IN c"
+        );
+        Ok(())
+    }
+}
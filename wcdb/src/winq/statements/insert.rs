@@ -0,0 +1,225 @@
+use libwcdb_sys::CPPStatementInsert;
+
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsColumn, AsExpression, AsSchema, AsSelect, IntoName},
+        identifier::WithRawIdentifier,
+        identifiers::{column::Column, expression::Expression, schema::Schema, upsert::Upsert},
+        types::Conflict,
+    },
+};
+
+use super::{statement, with};
+
+statement!(StatementInsert<CPPStatementInsert>);
+
+impl StatementInsert {
+    pub fn new() -> StatementInsert {
+        let ptr = unsafe { libwcdb_sys::WCDBStatementInsertCreate() };
+        StatementInsert(ptr.into())
+    }
+
+    with!(
+        WCDBStatementInsertConfigWith,
+        WCDBStatementInsertConfigRecursive
+    );
+
+    /// `INSERT INTO table`
+    pub fn insert_into<N: IntoName>(self, table: N) -> Result<StatementInsert> {
+        table.with_name(|table| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBStatementInsertConfigTable(t, table.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// `INSERT INTO schema.table`
+    pub fn of<T: AsSchema>(self, schema: T) -> Result<StatementInsert> {
+        Ok(self.of_inner(schema.as_schema()?))
+    }
+
+    fn of_inner(self, schema: Schema) -> StatementInsert {
+        (&self, &schema)
+            .with_raw(|(t, s)| unsafe { libwcdb_sys::WCDBStatementInsertConfigSchema(t, s) });
+        self
+    }
+
+    /// `INSERT OR conflict INTO ...`
+    pub fn conflict(self, conflict: Conflict) -> StatementInsert {
+        self.with_raw(|t| unsafe {
+            libwcdb_sys::WCDBStatementInsertConfigConfiction(t, conflict.c())
+        });
+        self
+    }
+
+    /// `INSERT INTO table AS alias`
+    pub fn r#as<N: IntoName>(self, alias: N) -> Result<StatementInsert> {
+        alias.with_name(|alias| {
+            self.with_raw(|t| unsafe {
+                libwcdb_sys::WCDBStatementInsertConfigAlias(t, alias.as_ptr())
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// `(column, ...)`
+    pub fn columns<T, I>(self, columns: I) -> StatementInsert
+    where
+        T: AsColumn,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.as_column()).collect();
+        self.columns_inner(columns)
+    }
+
+    fn columns_inner(self, columns: Vec<Column>) -> StatementInsert {
+        (&self, &columns).with_raw(|(t, c)| unsafe {
+            libwcdb_sys::WCDBStatementInsertConfigColumns(t, c.as_ptr(), c.len() as _)
+        });
+        self
+    }
+
+    /// `VALUES (value, ...)`. Call it repeatedly to insert several rows.
+    pub fn values<T, I>(self, values: I) -> StatementInsert
+    where
+        T: AsExpression,
+        I: IntoIterator<Item = T>,
+    {
+        let values = values.into_iter().map(|v| v.as_expression()).collect();
+        self.values_inner(values)
+    }
+
+    fn values_inner(self, values: Vec<Expression>) -> StatementInsert {
+        (&self, &values).with_raw(|(t, v)| unsafe {
+            libwcdb_sys::WCDBStatementInsertConfigValues(t, v.as_ptr(), v.len() as _)
+        });
+        self
+    }
+
+    /// `VALUES (?1, ?2, ...)` with `count` bind parameters.
+    pub fn values_with_bind_parameters(self, count: usize) -> StatementInsert {
+        self.with_raw(|t| unsafe {
+            libwcdb_sys::WCDBStatementInsertConfigValuesWithBindParameters(t, count as _)
+        });
+        self
+    }
+
+    /// `INSERT INTO table SELECT ...`
+    pub fn select<S: AsSelect>(self, select: S) -> StatementInsert {
+        (&self, &select)
+            .with_raw(|(t, s)| unsafe { libwcdb_sys::WCDBStatementInsertConfigSelect(t, s) });
+        self
+    }
+
+    /// `DEFAULT VALUES`
+    pub fn default_values(self) -> StatementInsert {
+        self.with_raw(|t| unsafe { libwcdb_sys::WCDBStatementInsertConfigDefaultValues(t) });
+        self
+    }
+
+    /// `ON CONFLICT ...`, which should follow the values.
+    pub fn upsert(self, upsert: Upsert) -> StatementInsert {
+        (&self, &upsert)
+            .with_raw(|(t, u)| unsafe { libwcdb_sys::WCDBStatementInsertConfigUpsert(t, u) });
+        self
+    }
+}
+
+impl Default for StatementInsert {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Result;
+    use crate::winq::{
+        expression_operable::ExpressionOperable,
+        identifiers::{
+            bind::BindParameter, common_table_expression::CommonTableExpression, eq_sql,
+        },
+        statements::select::StatementSelect,
+    };
+
+    fn columns() -> [Column; 2] {
+        [Column::new(c"a").unwrap(), Column::new(c"b").unwrap()]
+    }
+
+    #[test]
+    fn test_insert() -> Result<()> {
+        eq_sql!(
+            StatementInsert::new()
+                .insert_into(c"t")?
+                .columns(columns())
+                .values([Expression::integer(1), Expression::text(c"x")?]),
+            "INSERT INTO t(a, b) VALUES(1, 'x')"
+        );
+        eq_sql!(
+            StatementInsert::new()
+                .insert_into(c"t")?
+                .of("s")?
+                .conflict(Conflict::Replace)
+                .columns(columns())
+                .values_with_bind_parameters(2),
+            "INSERT OR REPLACE INTO s.t(a, b) VALUES(?1, ?2)"
+        );
+        eq_sql!(
+            StatementInsert::new()
+                .insert_into(c"t")?
+                .columns(columns())
+                .select(StatementSelect::new().select(columns()).from(["u"])?),
+            "INSERT INTO t(a, b) SELECT a, b FROM u"
+        );
+        eq_sql!(
+            StatementInsert::new().insert_into(c"t")?.default_values(),
+            "INSERT INTO t DEFAULT VALUES"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_with() -> Result<()> {
+        eq_sql!(
+            StatementInsert::new()
+                .with([CommonTableExpression::new(c"c")?
+                    .r#as(StatementSelect::new().select(columns()).from(["u"])?)])
+                .insert_into(c"t")?
+                .columns(columns())
+                .select(StatementSelect::new().select(columns()).from(["c"])?),
+            "WITH c AS(SELECT a, b FROM u) INSERT INTO t(a, b) SELECT a, b FROM c"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_upsert() -> Result<()> {
+        eq_sql!(
+            StatementInsert::new()
+                .insert_into(c"t")?
+                .columns(columns())
+                .values([BindParameter::new(1), BindParameter::new(2)])
+                .upsert(Upsert::new().on_conflict([Column::new(c"a")?]).do_nothing()),
+            "INSERT INTO t(a, b) VALUES(?1, ?2) ON CONFLICT(a) DO NOTHING"
+        );
+        eq_sql!(
+            StatementInsert::new()
+                .insert_into(c"t")?
+                .columns(columns())
+                .values_with_bind_parameters(2)
+                .upsert(
+                    Upsert::new()
+                        .on_conflict([Column::new(c"a")?])
+                        .do_update()
+                        .set([Column::new(c"b")?])
+                        .to(Column::new(c"b")?.r#in(c"excluded")?)
+                        .r#where(Column::new(c"b")?.ne(Column::new(c"b")?.r#in(c"excluded")?))
+                ),
+            "INSERT INTO t(a, b) VALUES(?1, ?2) ON CONFLICT(a) DO UPDATE SET b = excluded.b WHERE b != excluded.b"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+pub mod create_index;
+pub mod delete;
+pub mod insert;
+pub mod select;
+pub mod update;
+
+macro_rules! statement {
+    ($name:ident<$inner:ident>) => {
+        crate::winq::identifiers::identifier!($name<$inner>);
+
+        impl crate::winq::statement::Statement for $name {
+            fn raw_stmt(&self) -> *mut libwcdb_sys::CPPObject {
+                self.as_ptr()
+            }
+        }
+    };
+}
+
+/// Generates `with` and `with_recursive` for statements supporting common table expressions.
+macro_rules! with {
+    ($config_with:ident, $config_recursive:ident) => {
+        /// `WITH common_table_expression, ...`
+        pub fn with<I>(self, expressions: I) -> Self
+        where
+            I: IntoIterator<
+                Item = crate::winq::identifiers::common_table_expression::CommonTableExpression,
+            >,
+        {
+            let expressions: Vec<_> = expressions.into_iter().collect();
+            (&self, &expressions).with_raw(|(t, e)| unsafe {
+                libwcdb_sys::$config_with(t, e.as_ptr(), e.len() as _)
+            });
+            self
+        }
+
+        /// `WITH RECURSIVE common_table_expression, ...`
+        pub fn with_recursive<I>(self, expressions: I) -> Self
+        where
+            I: IntoIterator<
+                Item = crate::winq::identifiers::common_table_expression::CommonTableExpression,
+            >,
+        {
+            let statement = self.with(expressions);
+            statement.with_raw(|t| unsafe { libwcdb_sys::$config_recursive(t) });
+            statement
+        }
+    };
+}
+
+/// Generates `limit` and `offset` for a statement, returning `$limited`, which keeps the given
+/// limit when `offset` follows, or emits `LIMIT -1` for an `offset` on its own.
+macro_rules! limit {
+    ($limited:ident) => {
+        /// `LIMIT limit`
+        pub fn limit<T: crate::winq::convert::AsExpression>(self, limit: T) -> $limited {
+            $limited(self.limit_inner(limit.as_expression()))
+        }
+
+        /// `OFFSET offset`, with `LIMIT -1` since SQLite only accepts an offset after a limit.
+        pub fn offset<T: crate::winq::convert::AsExpression>(self, offset: T) -> $limited {
+            let limit = crate::winq::identifiers::expression::Expression::integer(-1);
+            $limited(self.limit_inner(limit).offset_inner(offset.as_expression()))
+        }
+    };
+}
+
+/// Generates a statement finished by `limit` or `offset`, which only accepts the other of them.
+macro_rules! limited {
+    ($(#[$meta:meta])* $name:ident<$statement:ident>) => {
+        $(#[$meta])*
+        pub struct $name($statement);
+
+        impl $name {
+            /// `LIMIT limit`
+            pub fn limit<T: crate::winq::convert::AsExpression>(self, limit: T) -> $name {
+                $name(self.0.limit_inner(limit.as_expression()))
+            }
+
+            /// `OFFSET offset`
+            pub fn offset<T: crate::winq::convert::AsExpression>(self, offset: T) -> $name {
+                $name(self.0.offset_inner(offset.as_expression()))
+            }
+
+            pub fn as_ptr(&self) -> *mut libwcdb_sys::CPPObject {
+                self.0.as_ptr()
+            }
+
+            pub fn description(&self) -> String {
+                self.0.description()
+            }
+
+            pub(crate) fn into_statement(self) -> $statement {
+                self.0
+            }
+        }
+
+        impl crate::winq::statement::Statement for $name {
+            fn raw_stmt(&self) -> *mut libwcdb_sys::CPPObject {
+                self.as_ptr()
+            }
+        }
+    };
+}
+
+use limit;
+use limited;
+use statement;
+use with;
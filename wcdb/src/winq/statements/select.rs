@@ -0,0 +1,691 @@
+use libwcdb_sys::CPPStatementSelect;
+
+use crate::{
+    error::Result,
+    winq::{
+        convert::{
+            AsExpression, AsOrderingTerm, AsResultColumn, AsSelect, AsTableOrSubquery, IntoName,
+            NonTextExpression,
+        },
+        identifier::WithRawIdentifier,
+        identifiers::{
+            expression::Expression, ordering_term::OrderingTerm, result_column::ResultColumn,
+            table_or_subquery::TableOrSubquery, window_def::WindowDef,
+        },
+        statement::Statement,
+    },
+};
+
+use super::{limit, limited, statement, with};
+
+statement!(StatementSelect<CPPStatementSelect>);
+
+macro_rules! compound {
+    ($(#[$meta:meta])* $name:ident = $ffi:ident) => {
+        $(#[$meta])*
+        pub fn $name(self) -> CompoundSelect {
+            self.with_raw(|t| unsafe { libwcdb_sys::$ffi(t) });
+            CompoundSelect(self)
+        }
+    };
+}
+
+macro_rules! core {
+    ($(#[$meta:meta])* $name:ident<$($g:ident: $b:path),*>($($p:ident: $t:ty),*)) => {
+        $(#[$meta])*
+        pub fn $name<$($g: $b),*>(self, $($p: $t),*) -> CompoundSelect {
+            CompoundSelect(self.0.$name($($p),*))
+        }
+    };
+}
+
+macro_rules! tail {
+    ($(#[$meta:meta])* $name:ident<$($g:ident: $b:path),*>($($p:ident: $t:ty),*) -> $r:ty) => {
+        $(#[$meta])*
+        pub fn $name<$($g: $b),*>(self, $($p: $t),*) -> $r {
+            self.0.$name($($p),*)
+        }
+    };
+}
+
+impl StatementSelect {
+    pub fn new() -> StatementSelect {
+        let ptr = unsafe { libwcdb_sys::WCDBStatementSelectCreate() };
+        StatementSelect(ptr.into())
+    }
+
+    with!(
+        WCDBStatementSelectConfigWith,
+        WCDBStatementSelectConfigRecursive
+    );
+
+    /// `SELECT result_column, ...`
+    pub fn select<T, I>(self, result_columns: I) -> StatementSelect
+    where
+        T: AsResultColumn,
+        I: IntoIterator<Item = T>,
+    {
+        let result_columns = result_columns
+            .into_iter()
+            .map(|c| c.as_result_column())
+            .collect();
+        self.select_inner(result_columns)
+    }
+
+    fn select_inner(self, result_columns: Vec<ResultColumn>) -> StatementSelect {
+        (&self, &result_columns).with_raw(|(t, c)| unsafe {
+            libwcdb_sys::WCDBStatementSelectConfigResultColumns(t, c.as_ptr(), c.len() as _)
+        });
+        self
+    }
+
+    /// `SELECT DISTINCT ...`
+    pub fn distinct(self) -> StatementSelect {
+        self.with_raw(|t| unsafe { libwcdb_sys::WCDBStatementSelectConfigDistinct(t) });
+        self
+    }
+
+    /// `FROM table_or_subquery, ...`
+    pub fn from<T, I>(self, tables: I) -> Result<StatementSelect>
+    where
+        T: AsTableOrSubquery,
+        I: IntoIterator<Item = T>,
+    {
+        let tables = tables
+            .into_iter()
+            .map(|t| t.as_table_or_subquery())
+            .collect::<Result<_>>()?;
+        Ok(self.tables_inner(tables))
+    }
+
+    fn tables_inner(self, tables: Vec<TableOrSubquery>) -> StatementSelect {
+        (&self, &tables).with_raw(|(t, q)| unsafe {
+            libwcdb_sys::WCDBStatementSelectConfigFromTableOrSubqueries(t, q.as_ptr(), q.len() as _)
+        });
+        self
+    }
+
+    /// `WHERE condition`
+    pub fn r#where<T: AsExpression>(self, condition: T) -> StatementSelect {
+        self.where_inner(condition.as_expression())
+    }
+
+    fn where_inner(self, condition: Expression) -> StatementSelect {
+        (&self, &condition)
+            .with_raw(|(t, c)| unsafe { libwcdb_sys::WCDBStatementSelectConfigWhere(t, c) });
+        self
+    }
+
+    /// `GROUP BY expression, ...`
+    pub fn group_by<T, I>(self, expressions: I) -> StatementSelect
+    where
+        T: AsExpression,
+        I: IntoIterator<Item = T>,
+    {
+        let expressions = expressions.into_iter().map(|e| e.as_expression()).collect();
+        self.group_by_inner(expressions)
+    }
+
+    fn group_by_inner(self, expressions: Vec<Expression>) -> StatementSelect {
+        (&self, &expressions).with_raw(|(t, e)| unsafe {
+            libwcdb_sys::WCDBStatementSelectConfigGroups(t, e.as_ptr(), e.len() as _)
+        });
+        self
+    }
+
+    /// `HAVING condition`
+    pub fn having<T: AsExpression>(self, condition: T) -> StatementSelect {
+        self.having_inner(condition.as_expression())
+    }
+
+    fn having_inner(self, condition: Expression) -> StatementSelect {
+        (&self, &condition)
+            .with_raw(|(t, c)| unsafe { libwcdb_sys::WCDBStatementSelectConfigHaving(t, c) });
+        self
+    }
+
+    /// `VALUES (value, ...)`. Call it repeatedly to add more rows.
+    pub fn values<T, I>(self, values: I) -> StatementSelect
+    where
+        T: AsExpression,
+        I: IntoIterator<Item = T>,
+    {
+        let values = values.into_iter().map(|v| v.as_expression()).collect();
+        self.values_inner(values)
+    }
+
+    fn values_inner(self, values: Vec<Expression>) -> StatementSelect {
+        (&self, &values).with_raw(|(t, v)| unsafe {
+            libwcdb_sys::WCDBStatementSelectConfigValues(t, v.as_ptr(), v.len() as _)
+        });
+        self
+    }
+
+    compound!(
+        /// `UNION`, followed by the next select core.
+        /// It must be called before `order_by`, `limit` and `offset`.
+        union = WCDBStatementSelectConfigUnion
+    );
+    compound!(
+        /// `UNION ALL`, followed by the next select core.
+        /// It must be called before `order_by`, `limit` and `offset`.
+        union_all = WCDBStatementSelectConfigUnionAll
+    );
+    compound!(
+        /// `INTERSECT`, followed by the next select core.
+        /// It must be called before `order_by`, `limit` and `offset`.
+        intersect = WCDBStatementSelectConfigIntersect
+    );
+    compound!(
+        /// `EXCEPT`, followed by the next select core.
+        /// It must be called before `order_by`, `limit` and `offset`.
+        except = WCDBStatementSelectConfigExcept
+    );
+
+    /// `WINDOW name AS (window_def)`, which can be referred by `Expression::over_window`.
+    pub fn window<N: IntoName>(self, name: N, window_def: WindowDef) -> Result<StatementSelect> {
+        name.with_name(|name| {
+            (&self, &window_def).with_raw(|(t, w)| unsafe {
+                libwcdb_sys::WCDBStatementSelectConfigWindow(t, name.as_ptr());
+                libwcdb_sys::WCDBStatementSelectConfigAs(t, w);
+            })
+        })?;
+        Ok(self)
+    }
+
+    /// `ORDER BY ordering_term, ...`, which finishes the select into an `OrderedSelect`.
+    ///
+    /// `limit` and `offset` finish it into a `LimitedSelect`.
+    pub fn order_by<T, I>(self, orders: I) -> OrderedSelect
+    where
+        T: AsOrderingTerm,
+        I: IntoIterator<Item = T>,
+    {
+        let orders = orders.into_iter().map(|o| o.as_order()).collect();
+        OrderedSelect(self.order_by_inner(orders))
+    }
+
+    fn order_by_inner(self, orders: Vec<OrderingTerm>) -> StatementSelect {
+        (&self, &orders).with_raw(|(t, o)| unsafe {
+            libwcdb_sys::WCDBStatementSelectConfigOrders(t, o.as_ptr(), o.len() as _)
+        });
+        self
+    }
+
+    limit!(LimitedSelect);
+
+    fn limit_inner(self, limit: Expression) -> StatementSelect {
+        (&self, &limit)
+            .with_raw(|(t, l)| unsafe { libwcdb_sys::WCDBStatementSelectConfigLimitCount(t, l) });
+        self
+    }
+
+    fn offset_inner(self, offset: Expression) -> StatementSelect {
+        (&self, &offset)
+            .with_raw(|(t, o)| unsafe { libwcdb_sys::WCDBStatementSelectConfigLimitOffset(t, o) });
+        self
+    }
+}
+
+/// A compound select, whose next select core follows `union`, `union_all`, `intersect` or `except`.
+///
+/// `order_by`, `limit` and `offset` apply to the whole compound select, so they finish the
+/// compound into an `OrderedSelect` or a `LimitedSelect`.
+pub struct CompoundSelect(StatementSelect);
+
+impl CompoundSelect {
+    core!(
+        /// `SELECT result_column, ...`
+        select<T: AsResultColumn, I: IntoIterator<Item = T>>(result_columns: I)
+    );
+    core!(
+        /// `SELECT DISTINCT ...`
+        distinct<>()
+    );
+
+    /// `FROM table_or_subquery, ...`
+    pub fn from<T, I>(self, tables: I) -> Result<CompoundSelect>
+    where
+        T: AsTableOrSubquery,
+        I: IntoIterator<Item = T>,
+    {
+        Ok(CompoundSelect(self.0.from(tables)?))
+    }
+
+    core!(
+        /// `WHERE condition`
+        r#where<T: AsExpression>(condition: T)
+    );
+    core!(
+        /// `GROUP BY expression, ...`
+        group_by<T: AsExpression, I: IntoIterator<Item = T>>(expressions: I)
+    );
+    core!(
+        /// `HAVING condition`
+        having<T: AsExpression>(condition: T)
+    );
+    core!(
+        /// `VALUES (value, ...)`
+        values<T: AsExpression, I: IntoIterator<Item = T>>(values: I)
+    );
+
+    /// `WINDOW name AS (window_def)`
+    pub fn window<N: IntoName>(self, name: N, window_def: WindowDef) -> Result<CompoundSelect> {
+        Ok(CompoundSelect(self.0.window(name, window_def)?))
+    }
+
+    /// `UNION`, followed by the next select core.
+    pub fn union(self) -> CompoundSelect {
+        self.0.union()
+    }
+
+    /// `UNION ALL`, followed by the next select core.
+    pub fn union_all(self) -> CompoundSelect {
+        self.0.union_all()
+    }
+
+    /// `INTERSECT`, followed by the next select core.
+    pub fn intersect(self) -> CompoundSelect {
+        self.0.intersect()
+    }
+
+    /// `EXCEPT`, followed by the next select core.
+    pub fn except(self) -> CompoundSelect {
+        self.0.except()
+    }
+
+    tail!(
+        /// `ORDER BY ordering_term, ...` of the whole compound select.
+        order_by<T: AsOrderingTerm, I: IntoIterator<Item = T>>(orders: I) -> OrderedSelect
+    );
+    tail!(
+        /// `LIMIT limit` of the whole compound select.
+        limit<T: AsExpression>(limit: T) -> LimitedSelect
+    );
+    tail!(
+        /// `OFFSET offset` of the whole compound select.
+        offset<T: AsExpression>(offset: T) -> LimitedSelect
+    );
+
+    pub fn as_ptr(&self) -> *mut libwcdb_sys::CPPObject {
+        self.0.as_ptr()
+    }
+
+    pub fn description(&self) -> String {
+        self.0.description()
+    }
+}
+
+impl WithRawIdentifier<CPPStatementSelect> for CompoundSelect {
+    fn with_raw<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(CPPStatementSelect) -> R,
+    {
+        self.0.with_raw(f)
+    }
+}
+
+impl AsSelect for CompoundSelect {}
+
+impl Statement for CompoundSelect {
+    fn raw_stmt(&self) -> *mut libwcdb_sys::CPPObject {
+        self.as_ptr()
+    }
+}
+
+impl AsExpression for CompoundSelect {
+    fn as_expression(self) -> Expression {
+        Expression::subquery(self.0)
+    }
+}
+
+impl NonTextExpression for CompoundSelect {}
+
+impl AsTableOrSubquery for CompoundSelect {
+    fn as_table_or_subquery(self) -> Result<TableOrSubquery> {
+        Ok(TableOrSubquery::subquery(self.0))
+    }
+}
+
+/// A select finished by `order_by`, `limit` or `offset`, which can not be compounded any more,
+/// since `ORDER BY`, `LIMIT` and `OFFSET` must come after the last select core.
+pub struct OrderedSelect(StatementSelect);
+
+impl OrderedSelect {
+    tail!(
+        /// `ORDER BY ordering_term, ...`
+        order_by<T: AsOrderingTerm, I: IntoIterator<Item = T>>(orders: I) -> OrderedSelect
+    );
+    tail!(
+        /// `LIMIT limit`
+        limit<T: AsExpression>(limit: T) -> LimitedSelect
+    );
+    tail!(
+        /// `OFFSET offset`
+        offset<T: AsExpression>(offset: T) -> LimitedSelect
+    );
+
+    pub fn as_ptr(&self) -> *mut libwcdb_sys::CPPObject {
+        self.0.as_ptr()
+    }
+
+    pub fn description(&self) -> String {
+        self.0.description()
+    }
+
+    pub(crate) fn into_statement(self) -> StatementSelect {
+        self.0
+    }
+}
+
+impl WithRawIdentifier<CPPStatementSelect> for OrderedSelect {
+    fn with_raw<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(CPPStatementSelect) -> R,
+    {
+        self.0.with_raw(f)
+    }
+}
+
+impl AsSelect for OrderedSelect {}
+
+impl Statement for OrderedSelect {
+    fn raw_stmt(&self) -> *mut libwcdb_sys::CPPObject {
+        self.as_ptr()
+    }
+}
+
+impl AsExpression for OrderedSelect {
+    fn as_expression(self) -> Expression {
+        Expression::subquery(self.0)
+    }
+}
+
+impl NonTextExpression for OrderedSelect {}
+
+impl AsTableOrSubquery for OrderedSelect {
+    fn as_table_or_subquery(self) -> Result<TableOrSubquery> {
+        Ok(TableOrSubquery::subquery(self.0))
+    }
+}
+
+limited!(
+    /// A select finished by `limit` or `offset`, which can neither be compounded nor ordered any
+    /// more.
+    LimitedSelect<StatementSelect>
+);
+
+impl WithRawIdentifier<CPPStatementSelect> for LimitedSelect {
+    fn with_raw<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(CPPStatementSelect) -> R,
+    {
+        self.0.with_raw(f)
+    }
+}
+
+impl AsSelect for LimitedSelect {}
+
+impl AsExpression for LimitedSelect {
+    fn as_expression(self) -> Expression {
+        Expression::subquery(self.0)
+    }
+}
+
+impl NonTextExpression for LimitedSelect {}
+
+impl AsTableOrSubquery for LimitedSelect {
+    fn as_table_or_subquery(self) -> Result<TableOrSubquery> {
+        Ok(TableOrSubquery::subquery(self.0))
+    }
+}
+
+impl AsExpression for StatementSelect {
+    fn as_expression(self) -> Expression {
+        Expression::subquery(self)
+    }
+}
+
+impl NonTextExpression for StatementSelect {}
+
+impl AsSelect for StatementSelect {}
+
+impl Default for StatementSelect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Result;
+    use crate::winq::{
+        expression_operable::ExpressionOperable,
+        identifiers::{
+            column::Column, common_table_expression::CommonTableExpression, eq_sql,
+            frame_spec::FrameSpec, join::Join,
+        },
+        types::Order,
+    };
+
+    fn a() -> Column {
+        Column::new(c"a").unwrap()
+    }
+
+    #[test]
+    fn test_select() -> Result<()> {
+        eq_sql!(
+            StatementSelect::new().select([a()]).from(["t"])?,
+            "SELECT a FROM t"
+        );
+        eq_sql!(
+            StatementSelect::new()
+                .select([a()])
+                .distinct()
+                .from(["t"])?,
+            "SELECT DISTINCT a FROM t"
+        );
+        eq_sql!(
+            StatementSelect::new()
+                .select([Expression::count_all()])
+                .from(["t"])?
+                .r#where(a().gt(Expression::integer(1)))
+                .group_by([Column::new(c"b")?])
+                .having(Expression::count_all().gt(Expression::integer(2))),
+            "SELECT count(*) FROM t WHERE a > 1 GROUP BY b HAVING count(*) > 2"
+        );
+        eq_sql!(
+            StatementSelect::new()
+                .select([a()])
+                .from(["t"])?
+                .order_by([a().order(Order::Desc)])
+                .limit(Expression::integer(1))
+                .offset(Expression::integer(2)),
+            "SELECT a FROM t ORDER BY a DESC LIMIT 1 OFFSET 2"
+        );
+        eq_sql!(
+            StatementSelect::new().select([a()]).from(["t"])?.offset(2),
+            "SELECT a FROM t LIMIT -1 OFFSET 2"
+        );
+        eq_sql!(
+            StatementSelect::new()
+                .select([a()])
+                .from(["t"])?
+                .offset(2)
+                .limit(1),
+            "SELECT a FROM t LIMIT 1 OFFSET 2"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_values() {
+        eq_sql!(
+            StatementSelect::new().values([1, 2]).values([3, 4]),
+            "VALUES(1, 2), (3, 4)"
+        );
+    }
+
+    #[test]
+    fn test_compound() -> Result<()> {
+        let select = |table| StatementSelect::new().select([a()]).from([table]);
+        eq_sql!(
+            select("t")?.union().select([a()]).from(["u"])?,
+            "SELECT a FROM t UNION SELECT a FROM u"
+        );
+        eq_sql!(
+            select("t")?.union_all().select([a()]).from(["u"])?,
+            "SELECT a FROM t UNION ALL SELECT a FROM u"
+        );
+        eq_sql!(
+            select("t")?.intersect().select([a()]).from(["u"])?,
+            "SELECT a FROM t INTERSECT SELECT a FROM u"
+        );
+        eq_sql!(
+            select("t")?.except().select([a()]).from(["u"])?,
+            "SELECT a FROM t EXCEPT SELECT a FROM u"
+        );
+        eq_sql!(
+            select("t")?
+                .r#where(a().gt(1))
+                .union_all()
+                .select([a()])
+                .from(["u"])?
+                .r#where(a().lt(0))
+                .union()
+                .values([2])
+                .order_by([a().order(Order::Desc)])
+                .limit(10)
+                .offset(5),
+            "SELECT a FROM t WHERE a > 1 UNION ALL SELECT a FROM u WHERE a < 0 UNION VALUES(2) ORDER BY a DESC LIMIT 10 OFFSET 5"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ordered_is_terminal() {
+        // Only reached when `OrderedSelect` and `LimitedSelect` have no inherent `union`,
+        // `union_all`, `intersect` or `except`, so compounding after `order_by`, `limit` or
+        // `offset` fails to compile.
+        trait Terminal: Sized {
+            fn union(self) {}
+            fn union_all(self) {}
+            fn intersect(self) {}
+            fn except(self) {}
+        }
+        impl Terminal for OrderedSelect {}
+        impl Terminal for LimitedSelect {}
+
+        let ordered = || StatementSelect::new().values([1]).order_by([a()]);
+        let () = ordered().union();
+        let () = ordered().union_all();
+        let () = ordered().intersect();
+        let () = ordered().except();
+        let limited = || StatementSelect::new().values([1]).limit(1);
+        let () = limited().union();
+        let () = limited().union_all();
+        let () = limited().intersect();
+        let () = limited().except();
+        let () = StatementSelect::new()
+            .values([1])
+            .union()
+            .values([2])
+            .offset(1)
+            .union();
+    }
+
+    #[test]
+    fn test_recursive_compound() -> Result<()> {
+        let id = || Column::new(c"id").unwrap();
+        let tree = CommonTableExpression::new(c"tree")?.column(id()).r#as(
+            StatementSelect::new()
+                .select([id()])
+                .from(["folder"])?
+                .r#where(Column::new(c"parent")?.is_null())
+                .union_all()
+                .select([id().r#in(c"folder")?])
+                .from([Join::new("folder")?
+                    .join("tree")?
+                    .on(Column::new(c"parent")?
+                        .r#in(c"folder")?
+                        .eq(id().r#in(c"tree")?))])?,
+        );
+        eq_sql!(
+            StatementSelect::new()
+                .with_recursive([tree])
+                .select([id()])
+                .from(["tree"])?,
+            "WITH RECURSIVE tree(id) AS(SELECT id FROM folder WHERE parent ISNULL UNION ALL SELECT folder.id FROM folder JOIN tree ON folder.parent == tree.id) SELECT id FROM tree"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_with() -> Result<()> {
+        let cte = || {
+            CommonTableExpression::new(c"c")
+                .unwrap()
+                .column(Column::new(c"n").unwrap())
+                .r#as(StatementSelect::new().select([Expression::integer(1)]))
+        };
+        eq_sql!(
+            StatementSelect::new()
+                .with([cte()])
+                .select([Column::new(c"n")?])
+                .from(["c"])?,
+            "WITH c(n) AS(SELECT 1) SELECT n FROM c"
+        );
+        let n = || Column::new(c"n").unwrap();
+        let counter = CommonTableExpression::new(c"c")?.column(n()).r#as(
+            StatementSelect::new()
+                .select([Expression::integer(1)])
+                .union_all()
+                .select([n().add(1)])
+                .from(["c"])?
+                .r#where(n().lt(3)),
+        );
+        eq_sql!(
+            StatementSelect::new()
+                .with_recursive([counter])
+                .select([n()])
+                .from(["c"])?,
+            "WITH RECURSIVE c(n) AS(SELECT 1 UNION ALL SELECT n + 1 FROM c WHERE n < 3) SELECT n FROM c"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_window() -> Result<()> {
+        let running_total = Expression::window_function(c"sum")?
+            .invoke()
+            .argument(a())
+            .over_window(c"w")?;
+        eq_sql!(
+            StatementSelect::new()
+                .select([running_total])
+                .from(["t"])?
+                .window(
+                    c"w",
+                    WindowDef::new()
+                        .partition([Column::new(c"b")?])
+                        .order([Column::new(c"c")?])
+                        .frame_spec(
+                            FrameSpec::rows()
+                                .between_unbounded_preceding()
+                                .and_current_row()
+                        )
+                )?,
+            "SELECT sum(a) OVER w FROM t WINDOW w AS(PARTITION BY b ORDER BY c ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)"
+        );
+        eq_sql!(
+            StatementSelect::new()
+                .select([Expression::rank()
+                    .over(WindowDef::new().order([a().order(Order::Desc)]))
+                    .r#as(c"r")?])
+                .from(["t"])?,
+            "SELECT rank() OVER(ORDER BY a DESC) AS r FROM t"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+use libwcdb_sys::CPPStatementUpdate;
+
+use crate::{
+    error::Result,
+    winq::{
+        convert::{AsColumn, AsExpression, AsOrderingTerm, AsQualifiedTable},
+        identifier::WithRawIdentifier,
+        identifiers::{
+            column::Column, expression::Expression, ordering_term::OrderingTerm,
+            qualified_table::QualifiedTable,
+        },
+        types::Conflict,
+    },
+};
+
+use super::{limit, limited, statement, with};
+
+statement!(StatementUpdate<CPPStatementUpdate>);
+
+impl StatementUpdate {
+    pub fn new() -> StatementUpdate {
+        let ptr = unsafe { libwcdb_sys::WCDBStatementUpdateCreate() };
+        StatementUpdate(ptr.into())
+    }
+
+    with!(
+        WCDBStatementUpdateConfigWith,
+        WCDBStatementUpdateConfigRecursive
+    );
+
+    /// `UPDATE qualified_table`
+    pub fn update<T: AsQualifiedTable>(self, table: T) -> Result<StatementUpdate> {
+        Ok(self.update_inner(table.as_qualified_table()?))
+    }
+
+    fn update_inner(self, table: QualifiedTable) -> StatementUpdate {
+        (&self, &table)
+            .with_raw(|(t, q)| unsafe { libwcdb_sys::WCDBStatementUpdateConfigTable(t, q) });
+        self
+    }
+
+    /// `UPDATE OR conflict ...`
+    pub fn conflict(self, conflict: Conflict) -> StatementUpdate {
+        self.with_raw(|t| unsafe {
+            libwcdb_sys::WCDBStatementUpdateConfigConfiction(t, conflict.c())
+        });
+        self
+    }
+
+    /// `SET column` or `SET (column, ...)`, whose value is given by `to`.
+    pub fn set<T, I>(self, columns: I) -> StatementUpdate
+    where
+        T: AsColumn,
+        I: IntoIterator<Item = T>,
+    {
+        let columns = columns.into_iter().map(|c| c.as_column()).collect();
+        self.set_inner(columns)
+    }
+
+    fn set_inner(self, columns: Vec<Column>) -> StatementUpdate {
+        (&self, &columns).with_raw(|(t, c)| unsafe {
+            libwcdb_sys::WCDBStatementUpdateConfigColumns(t, c.as_ptr(), c.len() as _)
+        });
+        self
+    }
+
+    /// `= value`
+    pub fn to<T: AsExpression>(self, value: T) -> StatementUpdate {
+        self.value_inner(value.as_expression())
+    }
+
+    fn value_inner(self, value: Expression) -> StatementUpdate {
+        (&self, &value)
+            .with_raw(|(t, v)| unsafe { libwcdb_sys::WCDBStatementUpdateConfigValue(t, v) });
+        self
+    }
+
+    /// `WHERE condition`
+    pub fn r#where<T: AsExpression>(self, condition: T) -> StatementUpdate {
+        self.where_inner(condition.as_expression())
+    }
+
+    fn where_inner(self, condition: Expression) -> StatementUpdate {
+        (&self, &condition)
+            .with_raw(|(t, c)| unsafe { libwcdb_sys::WCDBStatementUpdateConfigCondition(t, c) });
+        self
+    }
+
+    /// `ORDER BY ordering_term, ...`
+    pub fn order_by<T, I>(self, orders: I) -> StatementUpdate
+    where
+        T: AsOrderingTerm,
+        I: IntoIterator<Item = T>,
+    {
+        let orders = orders.into_iter().map(|o| o.as_order()).collect();
+        self.order_by_inner(orders)
+    }
+
+    fn order_by_inner(self, orders: Vec<OrderingTerm>) -> StatementUpdate {
+        (&self, &orders).with_raw(|(t, o)| unsafe {
+            libwcdb_sys::WCDBStatementUpdateConfigOrders(t, o.as_ptr(), o.len() as _)
+        });
+        self
+    }
+
+    limit!(LimitedUpdate);
+
+    fn limit_inner(self, limit: Expression) -> StatementUpdate {
+        (&self, &limit)
+            .with_raw(|(t, l)| unsafe { libwcdb_sys::WCDBStatementUpdateConfigLimitCount(t, l) });
+        self
+    }
+
+    fn offset_inner(self, offset: Expression) -> StatementUpdate {
+        (&self, &offset)
+            .with_raw(|(t, o)| unsafe { libwcdb_sys::WCDBStatementUpdateConfigLimitOffset(t, o) });
+        self
+    }
+}
+
+limited!(
+    /// A update finished by `limit` or `offset`.
+    LimitedUpdate<StatementUpdate>
+);
+
+impl Default for StatementUpdate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::winq::{
+        expression_operable::ExpressionOperable,
+        identifiers::{common_table_expression::CommonTableExpression, eq_sql},
+        statements::select::StatementSelect,
+    };
+
+    #[test]
+    fn test_update() -> Result<()> {
+        eq_sql!(
+            StatementUpdate::new()
+                .update("t")?
+                .set([Column::new(c"a")?])
+                .to(Expression::integer(1))
+                .set([Column::new(c"b")?])
+                .to(Expression::text(c"x")?)
+                .r#where(Column::new(c"c")?.gt(Expression::integer(2))),
+            "UPDATE t SET a = 1, b = 'x' WHERE c > 2"
+        );
+        eq_sql!(
+            StatementUpdate::new()
+                .update("t")?
+                .conflict(Conflict::Ignore)
+                .set([Column::new(c"a")?])
+                .to(Expression::integer(1))
+                .order_by([Column::new(c"b")?])
+                .limit(Expression::integer(1))
+                .offset(Expression::integer(2)),
+            "UPDATE OR IGNORE t SET a = 1 ORDER BY b LIMIT 1 OFFSET 2"
+        );
+        eq_sql!(
+            StatementUpdate::new()
+                .update("t")?
+                .set([Column::new(c"a")?])
+                .to(Expression::integer(1))
+                .offset(Expression::integer(2)),
+            "UPDATE t SET a = 1 LIMIT -1 OFFSET 2"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_with() -> Result<()> {
+        eq_sql!(
+            StatementUpdate::new()
+                .with([CommonTableExpression::new(c"c")?.r#as(
+                    StatementSelect::new()
+                        .select([Column::new(c"id")?])
+                        .from(["u"])?
+                )])
+                .update("t")?
+                .set([Column::new(c"a")?])
+                .to(Expression::integer(1))
+                .r#where(Column::new(c"id")?.in_table(c"c")?),
+            "WITH c AS(SELECT id FROM u) UPDATE t SET a = 1 WHERE id IN c"
+        );
+        Ok(())
+    }
+}
@@ -81,3 +81,20 @@ impl TransactionType {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy)]
+pub enum MatchType {
+    Simple,
+    Full,
+    Partial,
+}
+
+impl MatchType {
+    pub fn c(&self) -> WCDBSyntaxMatchType {
+        match self {
+            MatchType::Simple => WCDBSyntaxMatchType_WCDBSyntaxMatchType_Simple,
+            MatchType::Full => WCDBSyntaxMatchType_WCDBSyntaxMatchType_Full,
+            MatchType::Partial => WCDBSyntaxMatchType_WCDBSyntaxMatchType_Partial,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+#[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     Null,
     Integer32(i32),
@@ -63,6 +64,12 @@ impl From<()> for Value {
     }
 }
 
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Integer32(value as _)
+    }
+}
+
 impl From<i32> for Value {
     fn from(value: i32) -> Self {
         Value::Integer32(value)
@@ -104,3 +111,12 @@ impl From<&[u8]> for Value {
         Value::Blob(value.to_vec())
     }
 }
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.into(),
+            None => Value::Null,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use wcdb::orm::TableCoding;
+
+#[derive(TableCoding)]
+struct Message {
+    #[wcdb(primary, auto_increment)]
+    id: String,
+}
+
+fn main() {}
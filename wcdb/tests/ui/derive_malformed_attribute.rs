@@ -0,0 +1,9 @@
+use wcdb::orm::TableCoding;
+
+#[derive(TableCoding)]
+struct Message {
+    #[wcdb(column_name = reply_to)]
+    reply: i64,
+}
+
+fn main() {}
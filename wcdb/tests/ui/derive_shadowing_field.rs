@@ -0,0 +1,9 @@
+use wcdb::orm::TableCoding;
+
+#[derive(TableCoding)]
+struct Message {
+    id: i64,
+    binding: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use wcdb::orm::TableCoding;
+
+#[derive(TableCoding)]
+struct Message {
+    #[wcdb(primary, primary_key)]
+    id: i64,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use wcdb::orm::TableCoding;
+
+struct Tags;
+
+#[derive(TableCoding)]
+struct Message {
+    id: i64,
+    tags: Tags,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use wcdb::winq::statements::select::StatementSelect;
+
+fn main() {
+    let limited = StatementSelect::new().values([1]).limit(1);
+    limited.order_by([1]);
+}
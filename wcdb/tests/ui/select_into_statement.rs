@@ -0,0 +1,6 @@
+use wcdb::winq::statements::select::StatementSelect;
+
+fn main() {
+    let ordered = StatementSelect::new().values([1]).order_by([1]);
+    let _: StatementSelect = ordered.into();
+}